@@ -309,6 +309,25 @@ pub enum MacroType {
     Any,
 }
 
+impl std::str::FromStr for MacroType {
+    type Err = RadError;
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let var = match text.to_lowercase().as_str() {
+            "function" => Self::Function,
+            "deterred" => Self::Deterred,
+            "runtime" => Self::Runtime,
+            "any" => Self::Any,
+            _ => {
+                return Err(RadError::InvalidConversion(format!(
+                    "Macro type, \"{}\" is not a valid type",
+                    text
+                )))
+            }
+        };
+        Ok(var)
+    }
+}
+
 /// File wrapper which hodls both path and File handle
 #[derive(Debug)]
 pub struct FileTarget {
@@ -66,6 +66,19 @@ pub static UNALLOWED_CHARS: Lazy<Regex> = Lazy::new(|| {
     Regex::new(r#"[a-zA-Z1-9\\_\*\^\|\(\)-=,:~!]"#).expect("Failed to create regex expression")
 });
 
+// Wrap related
+#[cfg(feature = "textwrap")]
+/// Environment variable for a base width of proportional wrapping
+pub const WRAP_BASE_WIDTH_ENV: &str = "RAD_WRAP_BASE_WIDTH";
+#[cfg(feature = "textwrap")]
+/// Default base width of proportional wrapping
+pub const WRAP_BASE_WIDTH: usize = 80;
+
+// Random related
+#[cfg(feature = "rand")]
+/// Environment variable for a seed of random macros
+pub const RANDOM_SEED_ENV: &str = "RAD_RANDOM_SEED";
+
 // Diff related
 #[cfg(feature = "debug")]
 /// Source file for diff operation
@@ -102,3 +115,6 @@ pub const ESR: [&str; 0] = [];
 
 /// Define keyword
 pub const DEFINE_KEYWORD: &str = "define";
+
+/// Default limit of macro nesting depth
+pub const DEFAULT_MACRO_DEPTH: usize = 256;
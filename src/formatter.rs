@@ -41,6 +41,67 @@ impl Formatter {
         Ok(table)
     }
 
+    /// Convert github formatted table into csv
+    ///
+    /// Separator row is stripped and escaped pipes are unescaped
+    pub fn table_to_csv(data: &str, newline: &str) -> RadResult<String> {
+        let mut rows = data
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(Formatter::gfm_row)
+            .collect::<Vec<_>>();
+        let is_separator = |row: &Vec<String>| {
+            row.iter().all(|cell| {
+                let dashes = cell.trim_start_matches(':').trim_end_matches(':');
+                !dashes.is_empty() && dashes.chars().all(|ch| ch == '-')
+            })
+        };
+        if rows.len() < 2 || !is_separator(&rows[1]) {
+            return Err(RadError::InvalidArgument(
+                "Untable requires a github formatted table with a separator row".to_string(),
+            ));
+        }
+        rows.remove(1);
+        let csv = rows
+            .iter()
+            .map(|row| row.iter().map(|cell| Formatter::csv_cell(cell)).join(","))
+            .join(newline);
+        Ok(csv)
+    }
+
+    /// Split a github formatted table row into trimmed cells
+    fn gfm_row(line: &str) -> Vec<String> {
+        let line = line.strip_prefix('|').unwrap_or(line);
+        let mut cells = vec![];
+        let mut cell = String::new();
+        let mut chars = line.chars().peekable();
+        while let Some(ch) = chars.next() {
+            match ch {
+                '\\' if chars.peek() == Some(&'|') => {
+                    cell.push('|');
+                    chars.next();
+                }
+                '|' => cells.push(std::mem::take(&mut cell).trim().to_string()),
+                _ => cell.push(ch),
+            }
+        }
+        // Trailing pipe is optional
+        if !cell.trim().is_empty() {
+            cells.push(cell.trim().to_string());
+        }
+        cells
+    }
+
+    /// Quote a csv cell if necessary
+    fn csv_cell(cell: &str) -> String {
+        if cell.contains([',', '"', '\n', '\r']) {
+            format!("\"{}\"", cell.replace('"', "\"\""))
+        } else {
+            cell.to_string()
+        }
+    }
+
     // ----------
     // Formatting methods start
     // <FORMAT>
@@ -42,6 +42,13 @@ impl<'logger> Logger<'logger> {
         }
     }
 
+    /// Clear trackers and statistics
+    pub fn reset(&mut self) {
+        self.current_input = ProcessInput::Stdin;
+        self.tracker_stack = TrackerStack::new();
+        self.stat = LoggerStat::default();
+    }
+
     /// Set assert mode
     pub fn set_assert(&mut self) {
         self.assert = true;
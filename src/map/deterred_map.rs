@@ -176,6 +176,8 @@ $assert($path(a,b,c),$exec(path,a,b,c))".to_string()),
                     DeterredMacroMap::assert_fail,
                     Some("Assert succeeds when text expansion yields error
 
+- An error caught by the assertion is neither logged nor propagated
+
 # NOT deterred
 
 # Arguments
@@ -187,6 +189,34 @@ $assert($path(a,b,c),$exec(path,a,b,c))".to_string()),
 $fassert($eval(Text is not allowd))".to_string()),
                 ),
             ),
+            (
+                "try".to_owned(),
+                DMacroSign::new(
+                    "try",
+                    ["a_fallback", "a_expr"],
+                    DeterredMacroMap::try_expand,
+                    Some("Expand an expression and use a fallback when the expansion fails
+
+- An error caught by try is logged as a sanity warning instead of being propagated
+- Logging can be disabled with processor's \"warn_try_errors\" option
+- Relay targets set by the failed expression are discarded
+
+# Expansion order
+
+1. a_expr
+2. a_fallback : Only when a_expr failed
+
+# Arguments
+
+- a_fallback : Text to expand when a_expr yields an error
+- a_expr     : An expression to try
+
+# Example
+
+$assert(none,$try(none,$eval(Text is not allowd)))
+$assert(3,$try(none,$eval(1+2)))".to_string()),
+                ),
+            ),
             (
                 "forby".to_owned(),
                 DMacroSign::new(
@@ -243,6 +273,34 @@ $assert(a+b+c+,$foreach($:()+,a,b,c))".to_string(),
                     ),
                 ),
             ),
+            (
+                "foreachi".to_owned(),
+                DMacroSign::new(
+                    "foreachi",
+                    ["a_body", "a_array^"],
+                    DeterredMacroMap::foreach_indexed,
+                    Some(
+                        "Iterate around given array with an index.
+
+An iterated value is bound to macro \":\" and a zero-based index is bound to
+macro \"a_index\"
+
+# Expansion order
+
+1. a_array : Expanded on time
+2. a_body : Split array by comma, and expanded by per item.
+
+# Arguments
+
+- a_body  : A body to be pasted as iterated item
+- a_array : An array to iterate ( trimmed )
+
+# Example
+
+$assert(0a1b2c,$foreachi($a_index()$:(),a,b,c))".to_string(),
+                    ),
+                ),
+            ),
             (
                 "forline".to_owned(),
                 DMacroSign::new(
@@ -305,7 +363,9 @@ $assert(1+2+3+,$forloop($:()+,1,3))".to_string()),
                     ["a_macro_name^", "a_array"],
                     Self::map_array,
                     Some(
-"Execute macro on each array item
+"Execute macro on each array item and join results with comma
+
+- Each item is passed to the macro as a single argument
 
 # NOT Deterred
 
@@ -317,7 +377,32 @@ $assert(1+2+3+,$forloop($:()+,1,3))".to_string()),
 # Example
 
 $define(m,a_src=$a_src()+)
-$assert(a+b+c,$map(m,a,b,c))".to_string()),
+$assert(\\*a+,b+,c+*\\,$map(m,a,b,c))".to_string()),
+                ),
+            ),
+            (
+                "filter".to_owned(),
+                DMacroSign::new(
+                    "filter",
+                    ["a_macro_name^", "a_array"],
+                    Self::filter_array,
+                    Some(
+"Keep array items that satisfy a predicate macro and join them with comma
+
+- Each item is passed to the predicate macro as a single argument
+- A predicate macro should return exactly \"true\" or \"false\"
+
+# NOT Deterred
+
+# Arguments
+
+- a_macro_name : A predicate macro name to execute ( trimmed )
+- a_array      : An array to filter
+
+# Example
+
+$define(short,a_src=$eval($len($a_src()) < 3))
+$assert(\\*a,bc*\\,$filter(short,a,bc,def))".to_string()),
                 ),
             ),
             (
@@ -531,6 +616,36 @@ $assert(I'm defined,$ifdefel(define,I'm defined,I'm NOT defined))
 $assert(I'm NOT defined,$ifdefel(defuo,I'm defined,I'm NOT defined))".to_string()),
                 ),
             ),
+            (
+                "default".to_owned(),
+                DMacroSign::new(
+                    "default",
+                    ["a_macro_name^", "a_args", "a_fallback"],
+                    DeterredMacroMap::default_macro,
+                    Some(
+"Execute a macro if it is defined, otherwise print a fallback text
+
+- A fallback is printed literally and never expanded
+- Existence is checked before execution, thus errors from the macro are propagated
+
+# Expansion order
+
+1. a_macro_name : Expanded on time
+2. a_args       : Only when a_macro_name is defined
+
+# Arguments
+
+- a_macro_name : A macro name to execute ( trimmed )
+- a_args       : Arguments to pass to the macro
+- a_fallback   : A text to print if the macro is NOT defined
+
+# Example
+
+$define(greet,a_name=Hello $a_name())
+$assert(Hello world,$default(greet,world,Hi))
+$assert(Hi,$default(greeet,world,Hi))".to_string()),
+                ),
+            ),
             (
                 "logm".to_owned(),
                 DMacroSign::new(
@@ -95,7 +95,7 @@ impl DeterredMacroMap {
         }
     }
 
-    /// Apply map on array
+    /// Apply map on array and join results with comma
     ///
     /// # Usage
     ///
@@ -112,14 +112,14 @@ impl DeterredMacroMap {
             let src = p.parse_and_strip(&mut ap, level, "map", &args[1])?;
             let array = src.split(',');
 
-            let mut acc = String::new();
+            let mut acc = vec![];
             for item in array {
-                acc.push_str(
-                    &p.execute_macro(level, "map", &macro_name, item)?
+                acc.push(
+                    p.execute_macro(level, "map", &macro_name, item)?
                         .unwrap_or_default(),
                 );
             }
-            Ok(Some(acc))
+            Ok(Some(acc.join(",")))
         } else {
             Err(RadError::InvalidArgument(
                 "map requires two arguments".to_owned(),
@@ -127,6 +127,46 @@ impl DeterredMacroMap {
         }
     }
 
+    /// Filter array items with a predicate macro
+    ///
+    /// # Usage
+    ///
+    /// $filter(macro_name,array)
+    pub(crate) fn filter_array(
+        args: &str,
+        level: usize,
+        p: &mut Processor,
+    ) -> RadResult<Option<String>> {
+        let mut ap = ArgParser::new().no_strip();
+        if let Some(args) = ap.args_with_len(args, 2) {
+            ap.set_strip(true);
+            let macro_name = p.parse_and_strip(&mut ap, level, "filter", &trim!(&args[0]))?;
+            let src = p.parse_and_strip(&mut ap, level, "filter", &args[1])?;
+            let array = src.split(',');
+
+            let mut acc = vec![];
+            for item in array {
+                let predicate = p
+                    .execute_macro(level, "filter", &macro_name, item)?
+                    .unwrap_or_default();
+                let keep = Utils::is_arg_true(&predicate).map_err(|_| {
+                    RadError::InvalidArgument(format!(
+                        "Filter's predicate should return true or false but returned \"{}\" for \"{}\"",
+                        predicate, item
+                    ))
+                })?;
+                if keep {
+                    acc.push(item);
+                }
+            }
+            Ok(Some(acc.join(",")))
+        } else {
+            Err(RadError::InvalidArgument(
+                "filter requires two arguments".to_owned(),
+            ))
+        }
+    }
+
     /// Apply map on lines
     ///
     /// # Usage
@@ -360,6 +400,44 @@ impl DeterredMacroMap {
         }
     }
 
+    /// Loop around given values and substitute iterators with the value and its index
+    ///
+    /// # Usage
+    ///
+    /// $foreachi($a_index():$:(),a,b,c)
+    pub(crate) fn foreach_indexed(
+        args: &str,
+        level: usize,
+        processor: &mut Processor,
+    ) -> RadResult<Option<String>> {
+        let mut ap = ArgParser::new().no_strip();
+        if let Some(args) = ap.args_with_len(args, 2) {
+            ap.set_strip(true);
+            let mut sums = String::new();
+            let body = &args[0];
+            let loop_src = processor.parse_and_strip(&mut ap, level, "foreachi", &args[1])?;
+            let loopable = trim!(&loop_src);
+            for (index, value) in loopable.as_ref().split(',').enumerate() {
+                // This overrides value
+                processor.add_new_local_macro(level, "a_index", &index.to_string());
+                processor.add_new_local_macro(level, ":", value);
+                let result = &processor.parse_and_strip(&mut ap, level, "foreachi", body)?;
+
+                sums.push_str(result);
+            }
+
+            // Clear local macro
+            processor.remove_local_macro(level, ":");
+            processor.remove_local_macro(level, "a_index");
+
+            Ok(Some(sums))
+        } else {
+            Err(RadError::InvalidArgument(
+                "Foreachi requires two argument".to_owned(),
+            ))
+        }
+    }
+
     /// Loop around given values split by new line and substitute iterators  with the value
     ///
     /// # Usage
@@ -618,6 +696,37 @@ impl DeterredMacroMap {
         }
     }
 
+    /// Execute a macro if defined or print a literal fallback
+    ///
+    /// # Usage
+    ///
+    /// $default(macro_name,args,fallback)
+    pub(crate) fn default_macro(
+        args: &str,
+        level: usize,
+        processor: &mut Processor,
+    ) -> RadResult<Option<String>> {
+        let mut ap = ArgParser::new().no_strip();
+        if let Some(args) = ap.args_with_len(args, 3) {
+            ap.set_strip(true);
+
+            let name =
+                trim!(&processor.parse_and_strip(&mut ap, level, "default", &args[0])?).to_string();
+
+            if processor.contains_macro(&name, MacroType::Any) {
+                let macro_args = processor.parse_and_strip(&mut ap, level, "default", &args[1])?;
+                processor.execute_macro(level, "default", &name, &macro_args)
+            } else {
+                // Fallback is not expanded
+                Ok(Some(ap.strip(&args[2])))
+            }
+        } else {
+            Err(RadError::InvalidArgument(
+                "default requires three arguments".to_owned(),
+            ))
+        }
+    }
+
     /// If env exists, then execute expresion
     ///
     /// # Usage
@@ -741,6 +850,53 @@ impl DeterredMacroMap {
         }
     }
 
+    /// Try expanding an expression and fallback on error
+    ///
+    /// # Usage
+    ///
+    /// $try(fallback,expression)
+    pub(crate) fn try_expand(
+        args: &str,
+        level: usize,
+        processor: &mut Processor,
+    ) -> RadResult<Option<String>> {
+        let mut ap = ArgParser::new().no_strip();
+        if let Some(args) = ap.args_with_len(args, 2) {
+            ap.set_strip(true);
+            let backup = processor.state.behaviour;
+            let cache_backup = processor.state.error_cache.take();
+            let relay_len = processor.state.relay.len();
+            processor.state.behaviour = ErrorBehaviour::Assert;
+
+            let result = processor.parse_and_strip(&mut ap, level, "try", &args[1]);
+            processor.state.behaviour = backup;
+            // Original error is cached while an assert failure is returned
+            let caught = std::mem::replace(&mut processor.state.error_cache, cache_backup);
+            match result {
+                Ok(expanded) => Ok(Some(expanded)),
+                Err(err) => {
+                    // Discard relay targets pushed by failed expression
+                    processor.state.relay.truncate(relay_len);
+                    if processor.state.warn_try_errors {
+                        processor.log_warning(
+                            &format!(
+                                "Try caught an error and used a fallback : {}",
+                                caught.unwrap_or(err)
+                            ),
+                            WarningType::Sanity,
+                        )?;
+                    }
+                    let fallback = processor.parse_and_strip(&mut ap, level, "try", &args[0])?;
+                    Ok(Some(fallback))
+                }
+            }
+        } else {
+            Err(RadError::InvalidArgument(
+                "Try requires two arguments".to_owned(),
+            ))
+        }
+    }
+
     /// Consume streaming
     ///
     /// # Usage
@@ -70,6 +70,297 @@ impl FunctionMacroMap {
                     Some(man_fun!("align.r4d")),
                 ),
             ),
+            (
+                "zfill".to_owned(),
+                FMacroSign::new(
+                    "zfill",
+                    ["a_width^", "a_number^"],
+                    Self::zero_fill,
+                    Some("Pad a number with leading zeros to a given width
+
+- A sign is kept in front of zeros
+- A number wider than the width is returned as it is
+
+# Arguments
+
+- a_width  : A total width in characters [Unsigned integer] ( trimmed )
+- a_number : A number to pad ( trimmed )
+
+# Example
+
+$assert(00042,$zfill(5,42))
+$assert(-0042,$zfill(5,-42))".to_string()),
+                ),
+            ),
+            (
+                "ordinal".to_owned(),
+                FMacroSign::new(
+                    "ordinal",
+                    ["a_number^"],
+                    Self::ordinal,
+                    Some("Append an english ordinal suffix to an integer
+
+- A sign is kept and an absolute value decides the suffix
+
+# Arguments
+
+- a_number : An integer ( trimmed )
+
+# Example
+
+$assert(21st,$ordinal(21))
+$assert(12th,$ordinal(12))
+$assert(-3rd,$ordinal(-3))".to_string()),
+                ),
+            ),
+            (
+                "urlenc".to_owned(),
+                FMacroSign::new(
+                    "urlenc",
+                    ["a_text"],
+                    Self::url_encode,
+                    Some("Percent encode text for urls
+
+- Every character except unreserved characters ( A-Z a-z 0-9 - . _ ~ ) is encoded
+
+# Arguments
+
+- a_text : Text to encode
+
+# Example
+
+$assert(hello%20world,$urlenc(hello world))".to_string()),
+                ),
+            ),
+            (
+                "urldec".to_owned(),
+                FMacroSign::new(
+                    "urldec",
+                    ["a_text"],
+                    Self::url_decode,
+                    Some("Decode percent encoded text
+
+# Arguments
+
+- a_text : Text to decode
+
+# Example
+
+$assert(hello world,$urldec(hello%20world))".to_string()),
+                ),
+            ),
+            (
+                "htmlesc".to_owned(),
+                FMacroSign::new(
+                    "htmlesc",
+                    ["a_text"],
+                    Self::html_escape,
+                    Some("Escape html special characters into named entities
+
+- Characters & < > \" ' are escaped
+
+# Arguments
+
+- a_text : Text to escape
+
+# Example
+
+$assert(&lt;b&gt;&amp;&lt;/b&gt;,$htmlesc(<b>&</b>))".to_string()),
+                ),
+            ),
+            (
+                "htmlunesc".to_owned(),
+                FMacroSign::new(
+                    "htmlunesc",
+                    ["a_text"],
+                    Self::html_unescape,
+                    Some("Unescape html entities into characters
+
+- Entities &amp; &lt; &gt; &quot; &apos; and &#39; are unescaped
+- Other entities are left as they are
+
+# Arguments
+
+- a_text : Text to unescape
+
+# Example
+
+$assert(<b>&</b>,$htmlunesc(&lt;b&gt;&amp;&lt;/b&gt;))".to_string()),
+                ),
+            ),
+            (
+                "jsonesc".to_owned(),
+                FMacroSign::new(
+                    "jsonesc",
+                    ["a_text"],
+                    Self::json_escape,
+                    Some("Escape text to be used inside a json string
+
+- Quotes, backslashes and control characters are escaped
+- Result is not wrapped with quotes
+
+# Arguments
+
+- a_text : Text to escape
+
+# Example
+
+$assert(say \\\"hi\\\"\\n,$jsonesc(say \"hi\"
+))".to_string()),
+                ),
+            ),
+            (
+                "slugify".to_owned(),
+                FMacroSign::new(
+                    "slugify",
+                    ["a_text"],
+                    Self::slugify,
+                    Some("Convert text into a url slug
+
+- Text is lowercased and unicode letters are kept
+- Runs of non alphanumeric characters are replaced with a single hyphen
+- Leading and trailing hyphens are removed
+
+# Arguments
+
+- a_text : Text to convert
+
+# Example
+
+$assert(hello-world,$slugify(Hello, World!))".to_string()),
+                ),
+            ),
+            (
+                "hash".to_owned(),
+                FMacroSign::new(
+                    "hash",
+                    ["a_content"],
+                    Self::hash,
+                    Some("Get a stable hex digest of content
+
+- The digest is a 64 bit FNV-1a hash which is identical across runs
+- The hash is not cryptographic and should not be used for security
+
+# Arguments
+
+- a_content : Content to hash
+
+# Example
+
+$assert(a430d84680aabd0b,$hash(hello))".to_string()),
+                ),
+            ),
+            (
+                "roman".to_owned(),
+                FMacroSign::new(
+                    "roman",
+                    ["a_number^"],
+                    Self::to_roman,
+                    Some("Convert an integer into a roman numeral
+
+# Arguments
+
+- a_number : An integer between 1 and 3999 ( trimmed )
+
+# Example
+
+$assert(XIV,$roman(14))
+$assert(MCMXCIV,$roman(1994))".to_string()),
+                ),
+            ),
+            (
+                "unroman".to_owned(),
+                FMacroSign::new(
+                    "unroman",
+                    ["a_numeral^"],
+                    Self::from_roman,
+                    Some("Convert a roman numeral into an integer
+
+- Numeral is case insensitive
+
+# Arguments
+
+- a_numeral : A roman numeral between I and MMMCMXCIX ( trimmed )
+
+# Example
+
+$assert(14,$unroman(XIV))
+$assert(1994,$unroman(mcmxciv))".to_string()),
+                ),
+            ),
+            (
+                "numfmt".to_owned(),
+                FMacroSign::new(
+                    "numfmt",
+                    ["a_number^", "a_separator?^"],
+                    Self::format_number,
+                    Some("Group digits of a number with a thousands separator
+
+- A fractional part is kept without separators
+- A sign is kept outside of grouping
+- Default separator is comma
+
+# Arguments
+
+- a_number    : A number to format ( trimmed )
+- a_separator : A separator character ( trimmed, optional )
+
+# Example
+
+$assert(\\*1,234,567*\\,$numfmt(1234567))
+$assert(-1.234.567.891,$numfmt(-1234567.891, .))".to_string()),
+                ),
+            ),
+            (
+                "box".to_owned(),
+                FMacroSign::new(
+                    "box",
+                    ["a_style?^", "a_content"],
+                    Self::draw_box,
+                    Some("Wrap content in a box frame
+
+- Frame is sized to the widest line by display width
+- Style can be either unicode or ascii, default is unicode
+- If a first argument is not a style, whole arguments are treated as content
+
+# Arguments
+
+- a_style   : A frame style [\"unicode\", \"ascii\"] ( trimmed, optional )
+- a_content : Content to wrap
+
+# Example
+
+$assert(\\*+----+
+|ab  |
+|abcd|
++----+*\\,$box(ascii,ab
+abcd))".to_string()),
+                ),
+            ),
+            (
+                "centerl".to_owned(),
+                FMacroSign::new(
+                    "centerl",
+                    ["a_width^", "a_lines"],
+                    Self::center_lines,
+                    Some("Center each line within a width
+
+- Lines are padded with leading spaces only
+- Line length is measured by display width
+- Lines wider than the width and empty lines are left unchanged
+
+# Arguments
+
+- a_width : A width to center lines within [Unsigned integer] ( trimmed )
+- a_lines : Lines to center
+
+# Example
+
+$assert(\\*  abc
+  abcd*\\,$centerl(7,abc
+abcd))".to_string()),
+                ),
+            ),
             (
                 "alignby".to_owned(),
                 FMacroSign::new(
@@ -124,6 +415,26 @@ impl FunctionMacroMap {
                     Some(man_fun!("slice.r4d")),
                 ),
             ),
+            (
+                "stepa".to_owned(),
+                FMacroSign::new(
+                    "stepa",
+                    ["a_start^", "a_step^", "a_array"],
+                    Self::step_array,
+                    Some("Get every n-th item from an array starting from a given index
+
+# Arguments
+
+- a_start : A start index [Unsigned integer] ( trimmed )
+- a_step  : A step size [Unsigned integer] ( trimmed )
+- a_array : An array to process
+
+# Example
+
+$assert(\\*a,c,e*\\,$stepa(0,2,a,b,c,d,e,f))
+$assert(\\*b,e*\\,$stepa(1,3,a,b,c,d,e,f))".to_string()),
+                ),
+            ),
             (
                 "split".to_owned(),
                 FMacroSign::new(
@@ -133,6 +444,15 @@ impl FunctionMacroMap {
                     Some(man_fun!("split.r4d")),
                 ),
             ),
+            (
+                "splitn".to_owned(),
+                FMacroSign::new(
+                    "splitn",
+                    ["a_count^", "a_sep", "a_text"],
+                    Self::splitn,
+                    Some(man_fun!("splitn.r4d")),
+                ),
+            ),
             (
                 "strip".to_owned(),
                 FMacroSign::new(
@@ -187,6 +507,15 @@ impl FunctionMacroMap {
                     Some(man_fun!("cut.r4d")),
                 ),
             ),
+            (
+                "rcut".to_owned(),
+                FMacroSign::new(
+                    "rcut",
+                    ["a_sep", "a_index^","a_text"],
+                    Self::split_and_cut_right,
+                    Some(man_fun!("rcut.r4d")),
+                ),
+            ),
             (
                 "scut".to_owned(),
                 FMacroSign::new(
@@ -245,6 +574,24 @@ b
 ))".to_string()),
                 ),
             ),
+            (
+                "squeezec".to_owned(),
+                FMacroSign::new(
+                    "squeezec",
+                    ["a_char", "a_text"],
+                    Self::squeeze_char,
+                    Some("Collapse consecutive runs of a character into a single character
+
+# Arguments
+
+- a_char : A character to squeeze
+- a_text : Text to squeeze
+
+# Example
+
+$assert(a/b/c,$squeezec(/,a//b///c))".to_string()),
+                ),
+            ),
             (
                 "assert".to_owned(),
                 FMacroSign::new(
@@ -266,6 +613,88 @@ $assert(1,1)
 $assert(a,b)".to_string()),
                 ),
             ),
+            (
+                "assertempty".to_owned(),
+                FMacroSign::new(
+                    "assertempty",
+                    ["a_value^"],
+                    Self::assert_empty,
+                    Some("Assert that a value is empty, panics if the value is not empty
+
+# Arguments
+
+- a_value : A value to check ( trimmed )
+
+# Example
+
+% Succeed
+$assertempty( )
+% Fails
+$assertempty(a)".to_string()),
+                ),
+            ),
+            (
+                "assertfull".to_owned(),
+                FMacroSign::new(
+                    "assertfull",
+                    ["a_value^"],
+                    Self::assert_full,
+                    Some("Assert that a value is not empty, panics if the value is empty
+
+# Arguments
+
+- a_value : A value to check ( trimmed )
+
+# Example
+
+% Succeed
+$assertfull(a)
+% Fails
+$assertfull( )".to_string()),
+                ),
+            ),
+            (
+                "assertnum".to_owned(),
+                FMacroSign::new(
+                    "assertnum",
+                    ["a_expected^", "a_actual^", "a_tolerance^"],
+                    Self::assert_num,
+                    Some("Compare two numbers with a tolerance, panics if the difference exceeds the tolerance
+
+# Arguments
+
+- a_expected  : An expected number [Real number] ( trimmed )
+- a_actual    : An actual number [Real number] ( trimmed )
+- a_tolerance : An allowed difference [Real number] ( trimmed )
+
+# Example
+
+% Succeed
+$assertnum(0.3,0.30000000000000004,0.0001)
+% Fails
+$assertnum(1,1.1,0.01)".to_string()),
+                ),
+            ),
+            (
+                "bodylen".to_owned(),
+                FMacroSign::new(
+                    "bodylen",
+                    ["a_macro_name^"],
+                    Self::body_length,
+                    Some("Get a length of a runtime macro's body. This counts utf8 characters not ascii.
+
+# Return : Unsigned integer
+
+# Arguments
+
+- a_macro_name : A runtime macro name to get body length from ( trimmed )
+
+# Example
+
+$define(greet=가나다)
+$assert(3,$bodylen(greet))".to_string()),
+                ),
+            ),
             (
                 "capture".to_owned(),
                 FMacroSign::new(
@@ -407,29 +836,149 @@ $assert($ceil(3.1),4)".to_string()),
                 ),
             ),
             (
-                "chars".to_owned(),
+                "absn".to_owned(),
                 FMacroSign::new(
-                    "chars",
-                    ["a_text^"],
-                    Self::chars_array,
-                    Some("Get a characters array from text
+                    "absn",
+                    ["a_number^"],
+                    Self::absolute_number,
+                    Some("Get an absolute value of a number
+
+- This is different from \"abs\" which gets an absolute path
 
 # Arguments
 
-- a_text : Text to get a chars array from ( trimmed )
+- a_number : A number to get an absolute value from [float] ( trimmed )
 
 # Example
 
-$assert(\\*a,b,c,d,e*\\$chars(abcde))".to_string()),
+$assert($absn(-3),3)
+$assert($absn(-1.5),1.5)".to_string()),
                 ),
             ),
             (
-                "chomp".to_owned(),
+                "cumsum".to_owned(),
                 FMacroSign::new(
-                    "chomp",
-                    ["a_content"],
-                    Self::chomp,
-                    Some("Remove duplicate newlines from content
+                    "cumsum",
+                    ["a_array"],
+                    Self::cumulative_sum,
+                    Some("Get running totals of numbers in an array
+
+- Every element should be a number
+
+# Arguments
+
+- a_array : An array of numbers [float] ( trimmed )
+
+# Example
+
+$assert(\\*1,3,6,10*\\,$cumsum(1,2,3,4))
+$assert(\\*0.5,2*\\,$cumsum(0.5,1.5))".to_string()),
+                ),
+            ),
+            (
+                "mod".to_owned(),
+                FMacroSign::new(
+                    "mod",
+                    ["a_lvalue^", "a_rvalue^"],
+                    Self::modulo,
+                    Some("Get a remainder of an integer division
+
+# Return : Signed integer
+
+# Arguments
+
+- a_lvalue : A dividend [Signed integer] ( trimmed )
+- a_rvalue : A divisor which is not zero [Signed integer] ( trimmed )
+
+# Example
+
+$assert(1,$mod(7,3))".to_string()),
+                ),
+            ),
+            (
+                "idiv".to_owned(),
+                FMacroSign::new(
+                    "idiv",
+                    ["a_lvalue^", "a_rvalue^"],
+                    Self::integer_division,
+                    Some("Get a quotient of an integer division
+
+# Return : Signed integer
+
+# Arguments
+
+- a_lvalue : A dividend [Signed integer] ( trimmed )
+- a_rvalue : A divisor which is not zero [Signed integer] ( trimmed )
+
+# Example
+
+$assert(2,$idiv(7,3))".to_string()),
+                ),
+            ),
+            (
+                "clamp".to_owned(),
+                FMacroSign::new(
+                    "clamp",
+                    ["a_min^", "a_max^", "a_number^"],
+                    Self::clamp,
+                    Some("Clamp a number into a range
+
+# Arguments
+
+- a_min    : A lower bound [float] ( trimmed )
+- a_max    : An upper bound which is not smaller than min [float] ( trimmed )
+- a_number : A number to clamp [float] ( trimmed )
+
+# Example
+
+$assert(10,$clamp(0,10,15))
+$assert(0.5,$clamp(0.5,1,0.2))".to_string()),
+                ),
+            ),
+            (
+                "chunk".to_owned(),
+                FMacroSign::new(
+                    "chunk",
+                    ["a_size^", "a_text"],
+                    Self::chunk,
+                    Some("Split text into an array of groups with given characters count
+
+- A last group can be shorter than the size
+
+# Arguments
+
+- a_size : Characters count of a group [Unsigned integer] ( trimmed )
+- a_text : Text to split
+
+# Example
+
+$assert(\\*dead,beef,00*\\,$chunk(4,deadbeef00))".to_string()),
+                ),
+            ),
+            (
+                "chars".to_owned(),
+                FMacroSign::new(
+                    "chars",
+                    ["a_text^"],
+                    Self::chars_array,
+                    Some("Get a characters array from text
+
+# Arguments
+
+- a_text : Text to get a chars array from ( trimmed )
+
+# Example
+
+$assert(\\*a,b,c,d,e*\\$chars(abcde))".to_string()),
+                ),
+            ),
+            (
+                "chomp".to_owned(),
+                FMacroSign::new(
+                    "chomp",
+                    ["a_content"],
+                    Self::chomp,
+                    Some("Remove duplicate newlines from content
 
 # Arguments
 
@@ -543,6 +1092,71 @@ $assert(3,$countl(1
 3))".to_string()),
                 ),
             ),
+            (
+                "freq".to_owned(),
+                FMacroSign::new(
+                    "freq",
+                    ["a_array"],
+                    Self::frequency,
+                    Some("Get frequencies of array elements
+
+- Result is sorted by descending count and then by element
+
+# Arguments
+
+- a_array : An array to count elements from
+
+# Example
+
+$assert(\\*a:3,b:2,c:1*\\,$freq(a,b,a,c,a,b))".to_string()),
+                ),
+            ),
+            (
+                "wc".to_owned(),
+                FMacroSign::new(
+                    "wc",
+                    ["a_content"],
+                    Self::word_count,
+                    Some("Get counts of lines, words and characters separated by spaces
+
+# Return : Unsigned integers
+
+# Arguments
+
+- a_content : Content to get counts from
+
+# Example
+
+$assert(2 3 7,$wc(a b
+cde))
+$assert(1 1 2,$wc(a
+))".to_string()),
+                ),
+            ),
+            (
+                "countm".to_owned(),
+                FMacroSign::new(
+                    "countm",
+                    ["a_expr", "a_content"],
+                    Self::count_matches,
+                    Some("Count total matches of an expression in content
+
+- Matches are counted across a whole content, not per line
+- This returns the same result as findm but is named as a counting macro
+
+# Return : Unsigned integer
+
+# Arguments
+
+- a_expr    : A regex expression to match
+- a_content : Content to count matches from
+
+# Example
+
+$assert(3,$countm(o,foo
+bot))".to_string()),
+                ),
+            ),
             (
                 "dnl".to_owned(),
                 FMacroSign::new(
@@ -580,6 +1194,29 @@ $declare(first,second)
 $assert($first(),$empty())".to_string()),
                 ),
             ),
+            (
+                "defined".to_owned(),
+                FMacroSign::new(
+                    "defined",
+                    ["a_macro_name^", "a_macro_type?^"],
+                    Self::is_defined,
+                    Some("Check if a macro is defined
+
+- Available macro types are function, deterred, runtime and any
+
+# Return : Boolean
+
+# Arguments
+
+- a_macro_name : A macro name to check ( trimmed )
+- a_macro_type : A macro type to restrict the check ( trimmed, optional, default is any )
+
+# Example
+
+$assert(true,$defined(define))
+$assert(false,$defined(define,runtime))".to_string()),
+                ),
+            ),
             (
                 "docu".to_owned(),
                 FMacroSign::new(
@@ -698,6 +1335,41 @@ $assert($input(),test)
 $assert($input(true),/home/user/dir/test)".to_string()),
                 ),
             ),
+            (
+                "sourceloc".to_owned(),
+                FMacroSign::new(
+                    "sourceloc",
+                    ESR,
+                    Self::print_source_location,
+                    Some("Print a current input and line number as \"path:line\"
+
+- Stdin is printed as \"Stdin\"
+
+# Return : Source location
+
+# Example
+
+$assert(Stdin:1,$sourceloc())".to_string()),
+                ),
+            ),
+            (
+                "linenum".to_owned(),
+                FMacroSign::new(
+                    "linenum",
+                    ESR,
+                    Self::print_line_number,
+                    Some("Print a current line number of input
+
+- A line number is counted from a current input file including an included file
+- Use \"input\" macro to get an input path
+
+# Return : Unsigned integer
+
+# Example
+
+$assert(1,$linenum())".to_string()),
+                ),
+            ),
             (
                 "isempty".to_owned(),
                 FMacroSign::new(
@@ -788,6 +1460,70 @@ $assert(false,$iszero(1))".to_string()),
 $assert(true,$find(^abc,abcde))".to_string()),
                 ),
             ),
+            (
+                "has".to_owned(),
+                FMacroSign::new(
+                    "has",
+                    ["a_text", "a_source"],
+                    Self::has_substring,
+                    Some("Check if source contains a text.
+
+- Unlike find, a text is not a regex expression
+- An empty text is always contained
+
+# Return : Boolean
+
+# Arguments
+
+- a_text   : A literal text to find
+- a_source : Source to find from
+
+# Example
+
+$assert(true,$has(a.c,a.cde))
+$assert(false,$has(a.c,abcde))".to_string()),
+                ),
+            ),
+            (
+                "sw".to_owned(),
+                FMacroSign::new(
+                    "sw",
+                    ["a_prefix", "a_source"],
+                    Self::starts_with,
+                    Some("Check if source starts with a prefix. A prefix is not a regex expression
+
+# Return : Boolean
+
+# Arguments
+
+- a_prefix : A literal prefix to check
+- a_source : Source to check
+
+# Example
+
+$assert(true,$sw(ab,abc))".to_string()),
+                ),
+            ),
+            (
+                "ew".to_owned(),
+                FMacroSign::new(
+                    "ew",
+                    ["a_suffix", "a_source"],
+                    Self::ends_with,
+                    Some("Check if source ends with a suffix. A suffix is not a regex expression
+
+# Return : Boolean
+
+# Arguments
+
+- a_suffix : A literal suffix to check
+- a_source : Source to check
+
+# Example
+
+$assert(false,$ew(ab,abc))".to_string()),
+                ),
+            ),
             (
                 "findm".to_owned(),
                 FMacroSign::new(
@@ -798,6 +1534,8 @@ $assert(true,$find(^abc,abcde))".to_string()),
 "Get occurrences of expression from source. This returns 0 if there are no 
 occurrences.
 
+- This is same with countm
+
 # Return : Unsigned integer
 
 # Arguments
@@ -867,6 +1605,25 @@ b
 c))".to_string()),
                 ),
             ),
+            (
+                "getbody".to_owned(),
+                FMacroSign::new(
+                    "getbody",
+                    ["a_macro_name^"],
+                    Self::get_body,
+                    Some("Get a raw body of a runtime macro. The body is not expanded.
+
+# Arguments
+
+- a_macro_name : A runtime macro name to get body from ( trimmed )
+
+# Example
+
+$define(greet=Hello)
+$define(call=$greet())
+$assert(\\*$greet()*\\,$getbody(call))".to_string()),
+                ),
+            ),
             (
                 "grep".to_owned(),
                 FMacroSign::new(
@@ -886,6 +1643,27 @@ c))".to_string()),
 $assert(\\*a,b,c*\\,$grep([a-z],a,b,c,1,2))".to_string()),
                 ),
             ),
+            (
+                "grepg".to_owned(),
+                FMacroSign::new(
+                    "grepg",
+                    ["a_expr", "a_group^", "a_content"],
+                    Self::grep_group,
+                    Some(
+"Extract a named capture group from every match. Each capture is separated by 
+a newline and a match without the group yields an empty line
+
+# Arguments
+
+- a_expr    : A regex expression with a named group
+- a_group   : A name of a capture group ( trimmed )
+- a_content : Content to get matches from
+
+# Example
+
+$assert(a$nl()b,$grepg(key=(?P<value>\\w),value,key=a key=b))".to_string()),
+                ),
+            ),
             (
                 "grepl".to_owned(),
                 FMacroSign::new(
@@ -906,6 +1684,50 @@ a given expression
 $assert(2,$countl($grepl(Cargo,$syscmd(ls))))".to_string()),
                 ),
             ),
+            (
+                "grepv".to_owned(),
+                FMacroSign::new(
+                    "grepv",
+                    ["a_expr", "a_lines"],
+                    Self::grep_lines_inverted,
+                    Some(
+"Extract unmatched lines from given lines. This returns all lines that don't 
+match a given expression
+
+# Arguments
+
+- a_expr  : A regex expression to match
+- a_lines : Lines to get unmatched lines from
+
+# Example
+
+$assert(a$nl()c,$grepv(^#,a
+# comment
+c))".to_string()),
+                ),
+            ),
+            (
+                "grepn".to_owned(),
+                FMacroSign::new(
+                    "grepn",
+                    ["a_expr", "a_lines"],
+                    Self::grep_lines_numbered,
+                    Some(
+"Extract matched lines from given lines with line numbers. Each line is 
+prefixed with its 1-based line number and a colon
+
+# Arguments
+
+- a_expr  : A regex expression to match
+- a_lines : Lines to get matches from
+
+# Example
+
+$assert(2:b,$grepn(b,a
+b
+c))".to_string()),
+                ),
+            ),
             (
                 "halt".to_owned(),
                 FMacroSign::new(
@@ -1014,28 +1836,93 @@ Third))".to_string()),
                 ),
             ),
             (
-                "index".to_owned(),
+                "indentn".to_owned(),
                 FMacroSign::new(
-                    "index",
-                    ["a_index^", "a_array"],
-                    Self::index_array,
-                    Some("Get an indexed value from an array
+                    "indentn",
+                    ["a_count^", "a_indenter", "a_lines"],
+                    Self::indent_lines_repeated,
+                    Some("Indent lines with indenter repeated count times
 
-- A positive integer works as a normal index number
-- A negative integer works as an index from end ( -1 == len -1 )
+- Empty lines are not indented
 
 # Arguments
 
-- a_index : An index to get [Signed integer] ( trimmed )
-- a_array : Data source to index from
+- a_count    : A repeat count of indenter [Unsigned integer] ( trimmed )
+- a_indenter : An expression to put before lines
+- a_lines    : Lines to prepend indenter
 
 # Example
 
-$assert(ef,$index(2,ab,cd,ef))".to_string()),
+$assert(
+>>First
+
+>>Second,
+$indentn(2,>,First
+
+Second))".to_string()),
                 ),
             ),
             (
-                "indexl".to_owned(),
+                "enumerate".to_owned(),
+                FMacroSign::new(
+                    "enumerate",
+                    ["a_array"],
+                    Self::enumerate,
+                    Some("Prefix array elements with indices starting from 0
+
+- Use \"enumeratef\" to set a start index and a separator
+
+# Arguments
+
+- a_array : An array to enumerate
+
+# Example
+
+$assert(\\*0:a,1:b,2:c*\\,$enumerate(a,b,c))".to_string()),
+                ),
+            ),
+            (
+                "enumeratef".to_owned(),
+                FMacroSign::new(
+                    "enumeratef",
+                    ["a_start^", "a_separator", "a_array"],
+                    Self::enumerate_with_format,
+                    Some("Prefix array elements with indices from a start index and a separator
+
+# Arguments
+
+- a_start     : A start index [Signed integer] ( trimmed )
+- a_separator : A separator between an index and an element
+- a_array     : An array to enumerate
+
+# Example
+
+$assert(\\*1.a,2.b*\\,$enumeratef(1,.,a,b))".to_string()),
+                ),
+            ),
+            (
+                "index".to_owned(),
+                FMacroSign::new(
+                    "index",
+                    ["a_index^", "a_array"],
+                    Self::index_array,
+                    Some("Get an indexed value from an array
+
+- A positive integer works as a normal index number
+- A negative integer works as an index from end ( -1 == len -1 )
+
+# Arguments
+
+- a_index : An index to get [Signed integer] ( trimmed )
+- a_array : Data source to index from
+
+# Example
+
+$assert(ef,$index(2,ab,cd,ef))".to_string()),
+                ),
+            ),
+            (
+                "indexl".to_owned(),
                 FMacroSign::new(
                     "indexl",
                     ["a_index^", "a_lines"],
@@ -1093,6 +1980,26 @@ $import(def.r4f)".to_string()),
 $assert(a-b-c,$join(-,a,b,c))".to_string()),
                 ),
             ),
+            (
+                "zip".to_owned(),
+                FMacroSign::new(
+                    "zip",
+                    ["a_joiner","a_array"],
+                    Self::zip,
+                    Some("Zip first half of an array with second half of the array
+
+- An array should have even number of items
+
+# Arguments
+
+- a_joiner : A joiner to put between paired items
+- a_array  : An array to zip
+
+# Example
+
+$assert(\\*a=x,b=y,c=z*\\,$zip(=,a,b,c,x,y,z))".to_string()),
+                ),
+            ),
             (
                 "joinl".to_owned(),
                 FMacroSign::new(
@@ -1204,6 +2111,23 @@ $fassert($lc())".to_string()),
 $assert(Lorem ipsum dolor,$lipsum(3))".to_string()),
                 ),
             ),
+            (
+                "lipsums".to_owned(),
+                FMacroSign::new(
+                    "lipsums",
+                    ["a_sentence_count^"],
+                    Self::lipsum_sentences,
+                    Some("Create placeholder sentences. The order of placeholder is always same.
+
+# Arguments
+
+- a_sentence_count : Sentence counts of placeholder ( trimmed )
+
+# Example
+
+$assert(Lorem ipsum dolor sit amet consectetur adipiscing elit.,$lipsums(1))".to_string()),
+                ),
+            ),
             (
                 "log".to_owned(),
                 FMacroSign::new(
@@ -1241,6 +2165,82 @@ trigger a panic.
 $loge(This should not be reached)".to_string()),
                 ),
             ),
+            (
+                "camel".to_owned(),
+                FMacroSign::new(
+                    "camel",
+                    ["a_text^"],
+                    Self::camel_case,
+                    Some("Convert text into camelCase
+
+- Words are split by whitespaces, underscores, hyphens and case boundaries
+
+# Arguments
+
+- a_text : Text to convert ( trimmed )
+
+# Example
+
+$assert(fooBar,$camel(foo bar))".to_string()),
+                ),
+            ),
+            (
+                "kebab".to_owned(),
+                FMacroSign::new(
+                    "kebab",
+                    ["a_text^"],
+                    Self::kebab_case,
+                    Some("Convert text into kebab-case
+
+- Words are split by whitespaces, underscores, hyphens and case boundaries
+
+# Arguments
+
+- a_text : Text to convert ( trimmed )
+
+# Example
+
+$assert(foo-bar,$kebab(Foo Bar))".to_string()),
+                ),
+            ),
+            (
+                "pascal".to_owned(),
+                FMacroSign::new(
+                    "pascal",
+                    ["a_text^"],
+                    Self::pascal_case,
+                    Some("Convert text into PascalCase
+
+- Words are split by whitespaces, underscores, hyphens and case boundaries
+
+# Arguments
+
+- a_text : Text to convert ( trimmed )
+
+# Example
+
+$assert(FooBar,$pascal(foo bar))".to_string()),
+                ),
+            ),
+            (
+                "snake".to_owned(),
+                FMacroSign::new(
+                    "snake",
+                    ["a_text^"],
+                    Self::snake_case,
+                    Some("Convert text into snake_case
+
+- Words are split by whitespaces, underscores, hyphens and case boundaries
+
+# Arguments
+
+- a_text : Text to convert ( trimmed )
+
+# Example
+
+$assert(http_server,$snake(HTTPServer))".to_string()),
+                ),
+            ),
             (
                 "lower".to_owned(),
                 FMacroSign::new(
@@ -1315,6 +2315,147 @@ $assert(true,$lte(b,c))
 $assert(true,$lte(text,text))".to_string()),
                 ),
             ),
+            (
+                "macros".to_owned(),
+                FMacroSign::new(
+                    "macros",
+                    ["a_newline?^"],
+                    Self::list_runtime_macros,
+                    Some("Get sorted names of runtime macros
+
+- Volatile macros are also listed unless hygiene is aseptic
+- Names are delimited by comma by default
+
+# Arguments
+
+- a_newline : Whether to delimit names by newline [boolean] ( trimmed, optional )
+
+# Example
+
+$define(b=)
+$define(a=)
+$assert(\\*a,b*\\,$macros())".to_string()),
+                ),
+            ),
+            (
+                "avg".to_owned(),
+                FMacroSign::new(
+                    "avg",
+                    ["a_array"],
+                    Self::average,
+                    Some("Get an average of numbers in an array
+
+# Arguments
+
+- a_array : An array of numbers [float] ( trimmed )
+
+# Example
+
+$assert(2,$avg(1,2,3))
+$assert(1.5,$avg(1,2))".to_string()),
+                ),
+            ),
+            (
+                "product".to_owned(),
+                FMacroSign::new(
+                    "product",
+                    ["a_array"],
+                    Self::product,
+                    Some("Get a product of numbers in an array
+
+# Arguments
+
+- a_array : An array of numbers [float] ( trimmed )
+
+# Example
+
+$assert(24,$product(2,3,4))".to_string()),
+                ),
+            ),
+            (
+                "cartesian".to_owned(),
+                FMacroSign::new(
+                    "cartesian",
+                    ["a_lists"],
+                    Self::cartesian_product,
+                    Some("Get a cartesian product of two arrays
+
+- Two arrays are separated by a semicolon
+- An optional join string can be given after another semicolon
+- Elements of each pair are joined by a join string and pairs are joined by comma
+
+# Arguments
+
+- a_lists : Two arrays and an optional join string separated by semicolons
+
+# Example
+
+$assert(\\*a1,a2,b1,b2*\\,$cartesian(a,b;1,2))
+$assert(\\*a-1,b-1*\\,$cartesian(a,b;1;-))".to_string()),
+                ),
+            ),
+            (
+                "charseq".to_owned(),
+                FMacroSign::new(
+                    "charseq",
+                    ["a_start^", "a_end^"],
+                    Self::char_sequence,
+                    Some("Create an array of characters from start to end inclusively
+
+- Characters are ordered by unicode scalar values
+
+# Arguments
+
+- a_start : A start character ( trimmed )
+- a_end   : An end character ( trimmed )
+
+# Example
+
+$assert(\\*a,b,c,d,e*\\,$charseq(a,e))".to_string()),
+                ),
+            ),
+            (
+                "seq".to_owned(),
+                FMacroSign::new(
+                    "seq",
+                    ["a_start^", "a_end^", "a_step?^"],
+                    Self::sequence,
+                    Some("Create an array of integers from start to end inclusively
+
+- Default step is 1 for an ascending range and -1 for a descending range
+- A descending range requires a negative step
+
+# Arguments
+
+- a_start : A start of a range [Integer] ( trimmed )
+- a_end   : An end of a range [Integer] ( trimmed )
+- a_step  : A step between integers [Integer] ( trimmed, optional )
+
+# Example
+
+$assert(\\*1,2,3,4,5*\\,$seq(1,5))
+$assert(\\*0,4,8*\\,$seq(0,10,4))
+$assert(\\*3,2,1*\\,$seq(3,1))".to_string()),
+                ),
+            ),
+            (
+                "sum".to_owned(),
+                FMacroSign::new(
+                    "sum",
+                    ["a_array"],
+                    Self::sum,
+                    Some("Get a sum of numbers in an array
+
+# Arguments
+
+- a_array : An array of numbers [float] ( trimmed )
+
+# Example
+
+$assert(6,$sum(1,2,3))
+$assert(0.5,$sum(1,-0.5))".to_string()),
+                ),
+            ),
             (
                 "max".to_owned(),
                 FMacroSign::new(
@@ -1333,6 +2474,25 @@ $assert(eIsBigger,$max(aIsSmall,cIsMiddle,eIsBigger))
 $assert(5,$max(1,2,3,4,5))".to_string()),
                 ),
             ),
+            (
+                "maxn".to_owned(),
+                FMacroSign::new(
+                    "maxn",
+                    ["a_array"],
+                    Self::get_max_number,
+                    Some("Get a max number from a given array
+
+- This compares numerically while \"max\" compares lexicographically
+
+# Arguments
+
+- a_array : An array of numbers to get the highest value from [float] ( trimmed )
+
+# Example
+
+$assert(10,$maxn(9,10,-1))".to_string()),
+                ),
+            ),
             (
                 "min".to_owned(),
                 FMacroSign::new(
@@ -1351,6 +2511,25 @@ $assert(aIsSmall,$min(aIsSmall,cIsMiddle,eIsBigger))
 $assert(1,$min(1,2,3,4,5))".to_string()),
                 ),
             ),
+            (
+                "minn".to_owned(),
+                FMacroSign::new(
+                    "minn",
+                    ["a_array"],
+                    Self::get_min_number,
+                    Some("Get a min number from a given array
+
+- This compares numerically while \"min\" compares lexicographically
+
+# Arguments
+
+- a_array : An array of numbers to get the lowest value from [float] ( trimmed )
+
+# Example
+
+$assert(-1,$minn(9,10,-1))".to_string()),
+                ),
+            ),
             (
                 "name".to_owned(),
                 FMacroSign::new(
@@ -1633,6 +2812,60 @@ $pipeto(yum,YUM)
 $assert($-(yum),YUM)".to_string()),
                 ),
             ),
+            (
+                "peek".to_owned(),
+                FMacroSign::new(
+                    "peek",
+                    ["a_pipe_name?^"],
+                    Self::peek_pipe,
+                    Some("Get a piped value without consuming it
+
+- Peeking an empty pipe yields nothing
+
+# Arguments
+
+- a_pipe_name : A pipe name to peek ( trimmed, optional )
+
+# Example
+
+$pipe(Value)
+$assert(Value,$peek())
+$assert(Value,$-())".to_string()),
+                ),
+            ),
+            (
+                "pipelen".to_owned(),
+                FMacroSign::new(
+                    "pipelen",
+                    ESR,
+                    Self::pipe_length,
+                    Some("Get a count of pipes including named pipes
+
+# Return : Unsigned integer
+
+# Example
+
+$pipeclear()
+$pipe(Value)
+$pipeto(p1,Value)
+$assert(2,$pipelen())".to_string()),
+                ),
+            ),
+            (
+                "pipeclear".to_owned(),
+                FMacroSign::new(
+                    "pipeclear",
+                    ESR,
+                    Self::pipe_clear,
+                    Some("Clear all pipes including named pipes
+
+# Example
+
+$pipe(Value)
+$pipeclear()
+$assert(0,$pipelen())".to_string()),
+                ),
+            ),
             (
                 "prec".to_owned(),
                 FMacroSign::new(
@@ -1718,6 +2951,24 @@ $assert($rer(8. a
 $assert(\\*3,2,1*\\,$rev(1,2,3))".to_string()),
                 ),
             ),
+            (
+                "revc".to_owned(),
+                FMacroSign::new(
+                    "revc",
+                    ["a_text"],
+                    Self::reverse_chars,
+                    Some("Reverse characters of a text
+
+# Arguments
+
+- a_text : Text to reverse
+
+# Example
+
+$assert(cba,$revc(abc))
+$assert(녕안,$revc(안녕))".to_string()),
+                ),
+            ),
             (
                 "regex".to_owned(),
                 FMacroSign::new(
@@ -1803,7 +3054,26 @@ R4d,$repeat^(3,R4d$nl()))".to_string()),
                 ),
             ),
             (
-                "repl".to_owned(),
+                "repeatsep".to_owned(),
+                FMacroSign::new(
+                    "repeatsep",
+                    ["a_count^", "a_separator", "a_source"],
+                    Self::repeat_with_separator,
+                    Some("Repeat given source by given counts with a separator between repetitions
+
+# Arguments
+
+- a_count     : Counts of repetition [Unsigned integer] ( trimmed )
+- a_separator : A separator to put between repetitions
+- a_source    : Source text to repeat
+
+# Example
+
+$assert(?-?-?,$repeatsep(3,-,?))".to_string()),
+                ),
+            ),
+            (
+                "repl".to_owned(),
                 FMacroSign::new(
                     "repl",
                     ["a_macro_name^", "a_new_value"],
@@ -1891,13 +3161,14 @@ $source(def.env)".to_string()),
 
 # Arguments
 
-- a_sort_type : A sort type [\"asec\",\"desc\"] (trimmed)
+- a_sort_type : A sort type [\"asec\",\"desc\",\"num\"] (trimmed)
 - a_array     : An array to sort
 
 # Example
 
 $assert(\\*0,1,3,4,6,7,9*\\,$enl()
-$sort(asec,3,6,7,4,1,9,0))".to_string()),
+$sort(asec,3,6,7,4,1,9,0))
+$assert(\\*1,2,10*\\,$sort(num,2,10,1))".to_string()),
                 ),
             ),
             (
@@ -1910,7 +3181,7 @@ $sort(asec,3,6,7,4,1,9,0))".to_string()),
 
 # Arguments
 
-- a_sort_type : A sort type [\"asec\",\"desc\"] (trimmed)
+- a_sort_type : A sort type [\"asec\",\"desc\",\"num\"] (trimmed)
 - a_lines     : Lines to sort
 
 # Example
@@ -1921,6 +3192,56 @@ d
 c))".to_string()),
                 ),
             ),
+            (
+                "uniq".to_owned(),
+                FMacroSign::new(
+                    "uniq",
+                    ["a_global?^","a_array"],
+                    Self::unique_array,
+                    Some("Remove duplicate items from an array
+
+- By default, only consecutive duplicates are removed
+- Give \"true\" as a first argument to remove every duplicate
+- A leading \"true\" or \"false\" is always consumed as a flag. Give an explicit
+flag if an array starts with such a value
+
+# Arguments
+
+- a_global : Whether to remove all duplicates [boolean] (trimmed, optional)
+- a_array  : An array to deduplicate
+
+# Example
+
+$assert(\\*a,b,c*\\,$uniq(a,a,b,c,c))
+$assert(\\*a,b*\\,$uniq(true,a,b,a))
+$assert(\\*false,a*\\,$uniq(false,false,a))".to_string()),
+                ),
+            ),
+            (
+                "uniql".to_owned(),
+                FMacroSign::new(
+                    "uniql",
+                    ["a_global?^","a_lines"],
+                    Self::unique_lines,
+                    Some("Remove duplicate lines
+
+- By default, only consecutive duplicates are removed
+- Give \"true\" as a first argument to remove every duplicate
+- A leading \"true\" or \"false\" is always consumed as a flag. Give an explicit
+flag if lines start with such a value
+
+# Arguments
+
+- a_global : Whether to remove all duplicates [boolean] (trimmed, optional)
+- a_lines  : Lines to deduplicate
+
+# Example
+
+$assert(a$nl()b,$uniql(a
+a
+b))".to_string()),
+                ),
+            ),
             (
                 "space".to_owned(),
                 FMacroSign::new(
@@ -1989,6 +3310,28 @@ $assert(2,$ddf())
 $assert(0 ,$stt())".to_string()),
                 ),
             ),
+            (
+                "staticnew".to_owned(),
+                FMacroSign::new(
+                    "staticnew",
+                    ["a_macro_name^", "a_expr^"],
+                    Self::define_static_new,
+                    Some(
+"Create a static macro only if the name doesn't exist yet
+
+- Redefinition is always an error regardless of error behaviour
+
+# Arguments
+
+- a_macro_name : A macro to create ( trimmed )
+- a_expr       : An expression to bind to ( trimmed )
+
+# Example
+
+$staticnew(version,1.0.0)
+$assert(1.0.0,$version())".to_string()),
+                ),
+            ),
             (
                 "strict".to_owned(),
                 FMacroSign::new(
@@ -2131,6 +3474,127 @@ $assert=(
 )".to_string()),
                 ),
             ),
+            (
+                "iniget".to_owned(),
+                FMacroSign::new(
+                    "iniget",
+                    ["a_section^", "a_key^", "a_content"],
+                    Self::ini_get,
+                    Some(
+"Get a value from ini content
+
+- An empty section means keys before any section header
+- Lines starting with ; or # are comments
+- Keys and values are trimmed
+
+# Arguments
+
+- a_section : A section name ( trimmed )
+- a_key     : A key name ( trimmed )
+- a_content : Ini content to query
+
+# Example
+
+$assert(8080,$iniget(server,port,[server]
+; comment
+port = 8080))".to_string()),
+                ),
+            ),
+            (
+                "transpose".to_owned(),
+                FMacroSign::new(
+                    "transpose",
+                    ["a_csv"],
+                    Self::transpose,
+                    Some(
+"Swap rows and columns of csv content
+
+- Empty lines are ignored
+- Rows of unequal length yield an error in strict mode and are padded with empty cells otherwise
+
+# Arguments
+
+- a_csv : Csv content to transpose
+
+# Example
+
+$assert(\\*a,c
+b,d*\\,$transpose(a,b
+c,d))".to_string()),
+                ),
+            ),
+            (
+                "toc".to_owned(),
+                FMacroSign::new(
+                    "toc",
+                    ["a_content"],
+                    Self::table_of_contents,
+                    Some(
+"Create a table of contents from markdown headings
+
+- Headings are indented by their levels
+- Links are slugified same as the slugify macro
+- Headings inside fenced code blocks are ignored
+
+# Arguments
+
+- a_content : Markdown content to scan headings from
+
+# Example
+
+$assert(\\*- [Title](#title)
+  - [Sub section](#sub-section)*\\,$toc(# Title
+text
+## Sub section))".to_string()),
+                ),
+            ),
+            (
+                "fence".to_owned(),
+                FMacroSign::new(
+                    "fence",
+                    ["a_lang^", "a_content"],
+                    Self::fence,
+                    Some(
+"Wrap content in a fenced code block
+
+- A fence is longer than any backtick run inside content and at least three backticks
+
+# Arguments
+
+- a_lang    : A language tag which can be empty ( trimmed )
+- a_content : Content to wrap
+
+# Example
+
+$assert(\\*```rust
+let a = 1;
+```*\\,$fence(rust,let a = 1;))".to_string()),
+                ),
+            ),
+            (
+                "untable".to_owned(),
+                FMacroSign::new(
+                    "untable",
+                    ["a_table"],
+                    Self::untable,
+                    Some(
+"Convert a github formatted table into csv
+
+- A separator row is stripped and cells are trimmed
+- Escaped pipes ( \\| ) are converted into pipes
+
+# Arguments
+
+- a_table : A github formatted table to convert
+
+# Example
+
+$assert(\\*a,b
+1,2*\\,$untable(|a|b|
+|-|:-:|
+|1|2|))".to_string()),
+                ),
+            ),
             (
                 "tr".to_owned(),
                 FMacroSign::new(
@@ -2170,6 +3634,27 @@ $assert(Middle,$trim(
 ))".to_string()),
                 ),
             ),
+            (
+                "trimc".to_owned(),
+                FMacroSign::new(
+                    "trimc",
+                    ["a_chars", "a_text"],
+                    Self::trim_chars,
+                    Some(
+"Trim given characters from both ends of a text
+
+- Characters are matched literally, not as a regex
+
+# Arguments
+
+- a_chars : A set of characters to trim
+- a_text  : Text to trim
+
+# Example
+
+$assert(path,$trimc(/.,//path/.))".to_string()),
+                ),
+            ),
             (
                 "triml".to_owned(),
                 FMacroSign::new(
@@ -2189,6 +3674,26 @@ $assert(Upper$nl()Middle$nl()Last,$triml(    Upper
           Last))".to_string()),
                 ),
             ),
+            (
+                "trimrl".to_owned(),
+                FMacroSign::new(
+                    "trimrl",
+                    ["a_lines"],
+                    Self::trimrl,
+                    Some("Trim trailing whitespaces of each line
+
+- Leading indentation and line endings are preserved
+
+# Arguments
+
+- a_lines : Lines to trim
+
+# Example
+
+$assert($space(2)a$nl()b,$trimrl($space(2)a$space(3)
+b$space(1)))".to_string()),
+                ),
+            ),
             (
                 "trimla".to_owned(),
                 FMacroSign::new(
@@ -2232,6 +3737,50 @@ $space(5)Third)
 %   Third".to_string()),
                 ),
             ),
+            (
+                "stripcomment".to_owned(),
+                FMacroSign::new(
+                    "stripcomment",
+                    ["a_marker^", "a_lines"],
+                    Self::strip_comment,
+                    Some("Remove line comments from lines
+
+- Everything from a marker to the end of a line is removed
+- Markers inside double quoted strings are kept
+- Trailing whitespaces before a removed comment are also removed
+
+# Arguments
+
+- a_marker : A comment marker ( trimmed )
+- a_lines  : Lines to strip comments from
+
+# Example
+
+$assert(let a = \"//\";,$stripcomment(//,let a = \"//\"; // comment))".to_string()),
+                ),
+            ),
+            (
+                "dedent".to_owned(),
+                FMacroSign::new(
+                    "dedent",
+                    ["a_lines"],
+                    Self::dedent,
+                    Some("Remove common leading whitespaces from lines
+
+- Blank lines are ignored when finding a common prefix and become empty
+- Tabs and spaces are not treated as equal
+
+# Arguments
+
+- a_lines : Lines to dedent
+
+# Example
+
+$assert(\\*a
+  b*\\,$dedent(  a
+    b))".to_string()),
+                ),
+            ),
             (
                 "undef".to_owned(),
                 FMacroSign::new(
@@ -2361,6 +3910,50 @@ $assert(/home/user/dir,$env(HOME))"
                     ),
                 ),
             );
+            map.insert(
+                "envd".to_owned(),
+                FMacroSign::new(
+                    "envd",
+                    ["a_env_name^", "a_default"],
+                    Self::get_env_or_default,
+                    Some(
+                        "Get an environment variable or a default value if it is not defined
+
+# Auth : ENV
+
+# Arguments
+
+- a_env_name : An environment variable name to get (trimmed)
+- a_default  : A default value to return when the variable is not defined
+
+# Example
+
+$assert(/home/user/dir,$envd(HOME,/tmp))
+$assert(fallback,$envd(NOT_EXISTING_ENV,fallback))"
+                            .to_string(),
+                    ),
+                ),
+            );
+            map.insert(
+                "envlist".to_owned(),
+                FMacroSign::new(
+                    "envlist",
+                    ESR,
+                    Self::list_env_names,
+                    Some(
+                        "Get sorted names of environment variables delimited by comma
+
+- Values are not included
+
+# Auth : ENV
+
+# Example
+
+$envlist()"
+                            .to_string(),
+                    ),
+                ),
+            );
             map.insert(
                 "envset".to_owned(),
                 FMacroSign::new(
@@ -2571,6 +4164,9 @@ $assert(/tmp/rad.txt,$temp())"
                     Some(
                         "Write content to a file
 
+- If a_truncate is false, content is appended to the file
+- Appending requires an existing file
+
 # Auth : FOUT
 
 # Arguments
@@ -2581,7 +4177,138 @@ $assert(/tmp/rad.txt,$temp())"
 
 # Example
 
-$fileout(/tmp/some_file.txt,true,Hello World)"
+$fileout(/tmp/some_file.txt,true,Hello World)
+$fileout(/tmp/some_file.txt,false,Appended)"
+                            .to_string(),
+                    ),
+                ),
+            );
+            map.insert(
+                "copyfile".to_owned(),
+                FMacroSign::new(
+                    "copyfile",
+                    ["a_source^", "a_destination^"],
+                    Self::copy_file,
+                    Some(
+                        "Copy a file to a destination
+
+- A destination file is overwritten if it exists
+
+# Auth : FIN + FOUT
+
+# Arguments
+
+- a_source      : A file to copy ( trimmed )
+- a_destination : A path to copy to ( trimmed )
+
+# Example
+
+$copyfile(assets/logo.png,out/logo.png)"
+                            .to_string(),
+                    ),
+                ),
+            );
+            map.insert(
+                "movefile".to_owned(),
+                FMacroSign::new(
+                    "movefile",
+                    ["a_source^", "a_destination^"],
+                    Self::move_file,
+                    Some(
+                        "Move a file to a destination
+
+- A destination file is overwritten if it exists
+- A file is copied and then removed if it cannot be renamed across devices
+
+# Auth : FIN + FOUT
+
+# Arguments
+
+- a_source      : A file to move ( trimmed )
+- a_destination : A path to move to ( trimmed )
+
+# Example
+
+$movefile(out/draft.md,out/final.md)"
+                            .to_string(),
+                    ),
+                ),
+            );
+            map.insert(
+                "mkdir".to_owned(),
+                FMacroSign::new(
+                    "mkdir",
+                    ["a_path^"],
+                    Self::make_directory,
+                    Some(
+                        "Create a directory with all of its parent directories
+
+- Nothing happens if the directory already exists
+
+# Auth : FOUT
+
+# Arguments
+
+- a_path : A directory path to create ( trimmed )
+
+# Example
+
+$mkdir(/tmp/some/nested/dir)"
+                            .to_string(),
+                    ),
+                ),
+            );
+            map.insert(
+                "filesize".to_owned(),
+                FMacroSign::new(
+                    "filesize",
+                    ["a_file^", "a_human?^"],
+                    Self::get_file_size,
+                    Some(
+                        "Get a file's size in bytes
+
+- Human readable size uses binary units such as K, M and G
+
+# Auth : FIN
+
+# Return : Unsigned integer or human readable size
+
+# Arguments
+
+- a_file  : A file to get size ( trimmed )
+- a_human : Whether to format size in human readable form [boolean] ( trimmed, optional )
+
+# Example
+
+$filesize(some_file.txt)
+% 1234
+$filesize(some_file.txt,true)
+% 1.2K"
+                            .to_string(),
+                    ),
+                ),
+            );
+            map.insert(
+                "glob".to_owned(),
+                FMacroSign::new(
+                    "glob",
+                    ["a_pattern^"],
+                    Self::glob,
+                    Some(
+                        "Get sorted paths that match a glob pattern as csv
+
+- Supported wildcards are \"*\" and \"?\" which don't match a path separator
+- An empty string is returned if nothing matches
+
+# Auth : FIN
+
+# Arguments
+
+- a_pattern : A glob pattern to match paths ( trimmed )
+
+# Example
+
+$glob(src/*.rs)"
                             .to_string(),
                     ),
                 ),
@@ -2597,6 +4324,7 @@ $fileout(/tmp/some_file.txt,true,Hello World)"
 
 - A default path is a current working directory.
 - A defualt delimiter is comma.
+- Files are sorted by name.
 
 # Auth : FIN
 
@@ -2752,21 +4480,26 @@ $assert(00:33:40,$hms(2020))"
                 "ftime".to_owned(),
                 FMacroSign::new(
                     "ftime",
-                    ["a_file"],
+                    ["a_file^", "a_format?"],
                     Self::get_file_time,
                     Some(
                         "Get a file's last modified time.
 
+- A default format is \"%Y-%m-%d %H:%M:%S\"
+
 # Auth: FIN
 
 # Arguments
 
-- a_file : A file to get last modified time ( trimmed )
+- a_file   : A file to get last modified time ( trimmed )
+- a_format : A strftime format to print time with ( optional )
 
 # Example
 
 $ftime(some_file.txt)
-% 2022-07-07 19:07:06"
+% 2022-07-07 19:07:06
+$ftime(some_file.txt,%Y%m%d)
+% 20220707"
                             .to_string(),
                     ),
                 ),
@@ -2819,6 +4552,34 @@ $assert(1 + 2 = 3,$evalk(1 + 2 ))"
                     ),
                 ),
             );
+            map.insert(
+                "evalm".to_owned(),
+                FMacroSign::new(
+                    "evalm",
+                    ["a_expr"],
+                    Self::eval_with_macros,
+                    Some(
+                        "Evaluate an expression after substituting runtime macros
+
+- Identifiers that match runtime macros are replaced with expanded macro bodies
+- A substituted body is wrapped with parenthesis
+- A macro that substitutes itself recursively yields an error
+- Other identifiers are handled by evalexpr crate
+
+# Arguments
+
+- a_expr : An expression to evaluate
+
+# Example
+
+$static(width,10)
+$assert(20,$evalm(width * 2))
+$define(w=1+2)
+$assert(6,$evalm(w * 2))"
+                            .to_string(),
+                    ),
+                ),
+            );
             map.insert(
                 "pie".to_owned(),
                 FMacroSign::new("pie", ["a_expr"], Self::pipe_ire, Some("".to_string())),
@@ -2855,6 +4616,361 @@ rhoncus*\\,$wrap(20,$lipsum(10)))"
             ),
         );
 
+        #[cfg(feature = "textwrap")]
+        map.insert(
+            "wrapi".to_owned(),
+            FMacroSign::new(
+                "wrapi",
+                ["a_width^", "a_indent", "a_text"],
+                Self::wrap_indent,
+                Some(
+                    "Wrap text by width while indenting wrapped lines
+
+- A first line is not indented
+
+# Arguments
+
+- a_width  : A width(chars) of given texts ( trimmed )
+- a_indent : An indent to prepend to wrapped lines
+- a_text   : Text to wrap
+
+# Example
+
+$assert(\\*- Lorem ipsum
+  dolor sit*\\,$wrapi(13,  ,- $lipsum(4)))"
+                        .to_string(),
+                ),
+            ),
+        );
+
+        #[cfg(feature = "textwrap")]
+        map.insert(
+            "wraph".to_owned(),
+            FMacroSign::new(
+                "wraph",
+                ["a_width^", "a_text"],
+                Self::wrap_hyphenate,
+                Some(
+                    "Wrap text by width while breaking overly long words with a hyphen
+
+- Only words longer than the width are broken
+
+# Arguments
+
+- a_width : A width(chars) of given texts ( trimmed )
+- a_text  : Text to wrap
+
+# Example
+
+$assert(\\*see
+averyvery-
+longident-
+ifier*\\,$wraph(10,see averyverylongidentifier))"
+                        .to_string(),
+                ),
+            ),
+        );
+
+        #[cfg(feature = "textwrap")]
+        map.insert(
+            "wrapp".to_owned(),
+            FMacroSign::new(
+                "wrapp",
+                ["a_percent^", "a_text"],
+                Self::wrap_proportional,
+                Some(
+                    "Wrap text by a percentage of a base width
+
+- A base width is read from an environment variable \"RAD_WRAP_BASE_WIDTH\"
+- A default base width is 80
+
+# Arguments
+
+- a_percent : A percentage of a base width [1 ~ 100] ( trimmed )
+- a_text    : Text to wrap
+
+# Example
+
+% Wraps by 40 chars with a default base width
+$wrapp(50,$lipsum(20))"
+                        .to_string(),
+                ),
+            ),
+        );
+
+        #[cfg(feature = "base64")]
+        {
+            map.insert(
+                "b64enc".to_owned(),
+                FMacroSign::new(
+                    "b64enc",
+                    ["a_content"],
+                    Self::base64_encode,
+                    Some(
+                        "Encode content into a base64 string
+
+# Arguments
+
+- a_content : Content to encode
+
+# Example
+
+$assert(aGVsbG8=,$b64enc(hello))"
+                            .to_string(),
+                    ),
+                ),
+            );
+            map.insert(
+                "b64dec".to_owned(),
+                FMacroSign::new(
+                    "b64dec",
+                    ["a_content^"],
+                    Self::base64_decode,
+                    Some(
+                        "Decode a base64 string
+
+- Decoded bytes should be a valid utf8 text
+
+# Arguments
+
+- a_content : A base64 string to decode ( trimmed )
+
+# Example
+
+$assert(hello,$b64dec(aGVsbG8=))"
+                            .to_string(),
+                    ),
+                ),
+            );
+        }
+
+        #[cfg(feature = "json")]
+        map.insert(
+            "jsonget".to_owned(),
+            FMacroSign::new(
+                "jsonget",
+                ["a_path^", "a_json"],
+                Self::json_get,
+                Some(
+                    "Get a scalar value from json by a path
+
+- A path consists of dotted keys and bracketed indices
+- A string value is returned without quotes
+- Missing keys, type mismatches and non scalar values are errors
+
+# Arguments
+
+- a_path : A path to a value ( trimmed )
+- a_json : Json text to query
+
+# Example
+
+$assert(3,$jsonget(a.b[1].c,{\"a\":{\"b\":[1,{\"c\":3}]}}))"
+                        .to_string(),
+                ),
+            ),
+        );
+
+        #[cfg(feature = "toml")]
+        map.insert(
+            "tomlget".to_owned(),
+            FMacroSign::new(
+                "tomlget",
+                ["a_path^", "a_toml"],
+                Self::toml_get,
+                Some(
+                    "Get a scalar value from toml by a dotted path
+
+- A string value is returned without quotes
+- Missing keys and non scalar values are errors
+
+# Arguments
+
+- a_path : A dotted path to a value ( trimmed )
+- a_toml : Toml text to query
+
+# Example
+
+$assert(0.1.0,$tomlget(package.version,[package]
+version = \"0.1.0\"))"
+                        .to_string(),
+                ),
+            ),
+        );
+
+        #[cfg(feature = "yaml")]
+        map.insert(
+            "frontmatter".to_owned(),
+            FMacroSign::new(
+                "frontmatter",
+                ["a_key^", "a_document"],
+                Self::get_frontmatter,
+                Some(
+                    "Get a scalar value from yaml frontmatter of a document
+
+- Frontmatter is a leading yaml block delimited by \"---\" lines
+- Rest of the document is ignored
+- A document without frontmatter yields an error in strict mode and empty otherwise
+
+# Arguments
+
+- a_key      : A top level key of frontmatter ( trimmed )
+- a_document : A document to read
+
+# Example
+
+$assert(Hello,$frontmatter(title,---
+title: Hello
+---
+Content))"
+                        .to_string(),
+                ),
+            ),
+        );
+
+        #[cfg(feature = "uuid")]
+        {
+            map.insert(
+                "uuid".to_owned(),
+                FMacroSign::new(
+                    "uuid",
+                    ESR,
+                    Self::uuid_v4,
+                    Some(
+                        "Generate a random version 4 uuid
+
+# Example
+
+$uuid()"
+                            .to_string(),
+                    ),
+                ),
+            );
+            map.insert(
+                "uuid5".to_owned(),
+                FMacroSign::new(
+                    "uuid5",
+                    ["a_namespace^", "a_name"],
+                    Self::uuid_v5,
+                    Some(
+                        "Generate a deterministic version 5 uuid from a namespace and a name
+
+# Arguments
+
+- a_namespace : A namespace [\"dns\", \"url\", \"oid\", \"x500\", or a uuid ] ( trimmed )
+- a_name      : A name to hash
+
+# Example
+
+$assert(cfbff0d1-9375-5685-968c-48ce8b15ae17,$uuid5(dns,example.com))"
+                            .to_string(),
+                    ),
+                ),
+            );
+        }
+
+        #[cfg(feature = "rand")]
+        {
+            map.insert(
+                "choose".to_owned(),
+                FMacroSign::new(
+                    "choose",
+                    ["a_values"],
+                    Self::choose,
+                    Some(
+                        "Choose one of given values at random
+
+- Seed can be set with an environment variable \"RAD_RANDOM_SEED\"
+
+# Arguments
+
+- a_values : Comma separated values to choose from
+
+# Example
+
+$choose(apple,banana,cherry)"
+                            .to_string(),
+                    ),
+                ),
+            );
+            map.insert(
+                "shuffle".to_owned(),
+                FMacroSign::new(
+                    "shuffle",
+                    ["a_array"],
+                    Self::shuffle,
+                    Some(
+                        "Shuffle an array in a random order
+
+- Seed can be set with an environment variable \"RAD_RANDOM_SEED\"
+
+# Arguments
+
+- a_array : An array to shuffle
+
+# Example
+
+$shuffle(1,2,3,4)"
+                            .to_string(),
+                    ),
+                ),
+            );
+            map.insert(
+                "randint".to_owned(),
+                FMacroSign::new(
+                    "randint",
+                    ["a_min^", "a_max^"],
+                    Self::random_int,
+                    Some(
+                        "Get a random integer between min and max inclusively
+
+- Seed can be set with an environment variable \"RAD_RANDOM_SEED\"
+
+# Arguments
+
+- a_min : A minimum value [Integer] ( trimmed )
+- a_max : A maximum value [Integer] ( trimmed )
+
+# Example
+
+$assert(3,$randint(3,3))"
+                            .to_string(),
+                    ),
+                ),
+            );
+        }
+
+        #[cfg(feature = "debug")]
+        map.insert(
+            "diff".to_owned(),
+            FMacroSign::new(
+                "diff",
+                ["a_old", "a_new"],
+                Self::diff,
+                Some(
+                    "Compare two texts line by line
+
+- Removed lines are prefixed with \"- \"
+- Added lines are prefixed with \"+ \"
+- Unchanged lines are prefixed with two spaces
+
+# Arguments
+
+- a_old : An old text
+- a_new : A new text
+
+# Example
+
+$assert(\\*  a
+- b
++ c*\\,$diff(a
+b,a
+c))"
+                    .to_string(),
+                ),
+            ),
+        );
+
         #[cfg(feature = "hook")]
         {
             map.insert(
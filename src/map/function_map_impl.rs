@@ -17,7 +17,7 @@ use cindex::OutOption;
 use once_cell::sync::Lazy;
 use regex::Regex;
 use similar::DiffableStr;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 #[cfg(not(feature = "wasm"))]
 use std::fs::OpenOptions;
 use std::io::BufRead;
@@ -27,12 +27,98 @@ use std::path::{Path, PathBuf};
 #[cfg(not(feature = "wasm"))]
 use std::process::Command;
 use std::str::FromStr;
+use unicode_width::UnicodeWidthStr;
 
 // NOTE
 // Is this necessary?
 /// Types for align macros
 const ALIGN_TYPES: [&str; 3] = ["left", "right", "center"];
 
+/// Roman numeral symbols in descending order of value
+const ROMAN_NUMERALS: [(usize, &str); 13] = [
+    (1000, "M"),
+    (900, "CM"),
+    (500, "D"),
+    (400, "CD"),
+    (100, "C"),
+    (90, "XC"),
+    (50, "L"),
+    (40, "XL"),
+    (10, "X"),
+    (9, "IX"),
+    (5, "V"),
+    (4, "IV"),
+    (1, "I"),
+];
+
+/// Split text into lowercase words for case conversion
+///
+/// Acronym runs are kept as a word, e.g. "HTTPServer" becomes "http" and "server"
+fn split_case_words(text: &str) -> Vec<String> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut words = vec![];
+    let mut word = String::new();
+    for (index, &ch) in chars.iter().enumerate() {
+        if !ch.is_alphanumeric() {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            continue;
+        }
+        if ch.is_uppercase() && !word.is_empty() {
+            let prev = chars[index - 1];
+            let next_is_lower = chars.get(index + 1).is_some_and(|c| c.is_lowercase());
+            if !prev.is_uppercase() || next_is_lower {
+                words.push(std::mem::take(&mut word));
+            }
+        }
+        word.extend(ch.to_lowercase());
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
+
+/// Convert text into a lowercase slug joined by hyphens
+fn slugify_text(text: &str) -> String {
+    let mut slug = String::with_capacity(text.len());
+    let mut pending_hyphen = false;
+    for ch in text.chars() {
+        if ch.is_alphanumeric() {
+            if pending_hyphen && !slug.is_empty() {
+                slug.push('-');
+            }
+            pending_hyphen = false;
+            slug.extend(ch.to_lowercase());
+        } else {
+            pending_hyphen = true;
+        }
+    }
+    slug
+}
+
+/// Uppercase the first character of a word
+fn capitalize_word(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Convert an integer into a canonical roman numeral
+fn int_to_roman(mut number: usize) -> String {
+    let mut numeral = String::new();
+    for (value, symbol) in ROMAN_NUMERALS {
+        while number >= value {
+            numeral.push_str(symbol);
+            number -= value;
+        }
+    }
+    numeral
+}
+
 // ----------
 // rer related regexes
 //
@@ -62,6 +148,10 @@ static NUM_MATCH: Lazy<Regex> =
 /// Two lines match
 static TWO_NL_MATCH: Lazy<Regex> =
     Lazy::new(|| Regex::new(r#"(\n|\r\n)\s*(\n|\r\n)"#).expect("Failed to create tow nl regex"));
+/// Identifier match for macro substitution
+#[cfg(feature = "evalexpr")]
+static IDENT_MATCH: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"[A-Za-z_]\w*"#).expect("Failed to create identifier regex"));
 /// Patparator match
 static PATH_MATCH: Lazy<Regex> =
     Lazy::new(|| Regex::new(r#"(\\|/)"#).expect("Failed to create path separator matches"));
@@ -168,11 +258,30 @@ impl FunctionMacroMap {
         }
     }
 
+    /// Print current line number of input
+    ///
+    /// $linenum()
+    pub(crate) fn print_line_number(_: &str, p: &mut Processor) -> RadResult<Option<String>> {
+        Ok(Some(p.get_current_line_number().to_string()))
+    }
+
+    /// Print current input and line number
+    ///
+    /// $sourceloc()
+    pub(crate) fn print_source_location(_: &str, p: &mut Processor) -> RadResult<Option<String>> {
+        let input = match &p.state.current_input {
+            ProcessInput::Stdin => "Stdin".to_string(),
+            ProcessInput::File(path) => path.display().to_string(),
+        };
+        Ok(Some(format!("{}:{}", input, p.get_current_line_number())))
+    }
+
     /// Get a last modified time from a file
     ///
     /// # Usage
     ///
     /// $ftime(file_name.txt)
+    /// $ftime(file_name.txt,%Y%m%d)
     #[cfg(not(feature = "wasm"))]
     #[cfg(feature = "chrono")]
     pub(crate) fn get_file_time(
@@ -182,22 +291,88 @@ impl FunctionMacroMap {
         if !Utils::is_granted("ftime", AuthType::FIN, processor)? {
             return Ok(None);
         }
-        if let Some(args) = ArgParser::new().args_with_len(args, 1) {
-            let file = trim!(&args[0]);
-            let path = Path::new(file.as_ref());
-            if !path.exists() {
-                return Err(RadError::InvalidArgument(format!(
-                    "Cannot get a filetime from a non-existent file : \"{}\"",
-                    path.display()
-                )));
-            }
-            let time: chrono::DateTime<chrono::Utc> = std::fs::metadata(path)?.modified()?.into();
-            Ok(Some(time.format("%Y-%m-%d %H:%m:%S").to_string()))
-        } else {
-            Err(RadError::InvalidArgument(
-                "ftime requires an argument".to_owned(),
-            ))
+        let args = ArgParser::new().args_to_vec(args, ',', SplitVariant::Never);
+        if args.is_empty() || args.len() > 2 {
+            return Err(RadError::InvalidArgument(
+                "ftime requires one or two arguments".to_owned(),
+            ));
+        }
+        let file = trim!(&args[0]);
+        let path = Path::new(file.as_ref());
+        if !path.exists() {
+            return Err(RadError::InvalidArgument(format!(
+                "Cannot get a filetime from a non-existent file : \"{}\"",
+                path.display()
+            )));
+        }
+        let format = match args.get(1) {
+            Some(format) => format.as_str(),
+            None => "%Y-%m-%d %H:%M:%S",
+        };
+        // Invalid specifier makes formatting panic, thus check it beforehand
+        if chrono::format::StrftimeItems::new(format)
+            .any(|item| item == chrono::format::Item::Error)
+        {
+            return Err(RadError::InvalidArgument(format!(
+                "ftime got an invalid time format : \"{}\"",
+                format
+            )));
+        }
+        let time: chrono::DateTime<chrono::Utc> = std::fs::metadata(path)?.modified()?.into();
+        Ok(Some(time.format(format).to_string()))
+    }
+
+    /// Get a size of a file
+    ///
+    /// # Usage
+    ///
+    /// $filesize(file_name.txt)
+    /// $filesize(file_name.txt,true)
+    #[cfg(not(feature = "wasm"))]
+    pub(crate) fn get_file_size(
+        args: &str,
+        processor: &mut Processor,
+    ) -> RadResult<Option<String>> {
+        if !Utils::is_granted("filesize", AuthType::FIN, processor)? {
+            return Ok(None);
+        }
+        let args = ArgParser::new().args_to_vec(args, ',', SplitVariant::Never);
+        if args.is_empty() || args.len() > 2 {
+            return Err(RadError::InvalidArgument(
+                "filesize requires one or two arguments".to_owned(),
+            ));
+        }
+        let file = trim!(&args[0]);
+        let path = Path::new(file.as_ref());
+        if !path.is_file() {
+            return Err(RadError::InvalidArgument(format!(
+                "Cannot get a file size from a directory or non-existent file : \"{}\"",
+                path.display()
+            )));
+        }
+        Utils::check_file_sanity(processor, &path.canonicalize()?)?;
+        let human = match args.get(1) {
+            Some(human) => Utils::is_arg_true(human).map_err(|_| {
+                RadError::InvalidArgument(format!(
+                    "filesize requires a boolean value but given \"{}\"",
+                    human
+                ))
+            })?,
+            None => false,
+        };
+
+        let size = std::fs::metadata(path)?.len();
+        if !human || size < 1024 {
+            return Ok(Some(size.to_string()));
+        }
+        let units = ["K", "M", "G", "T", "P"];
+        let mut scaled = size as f64 / 1024.0;
+        let mut index = 0;
+        while scaled >= 1024.0 && index < units.len() - 1 {
+            scaled /= 1024.0;
+            index += 1;
         }
+        Ok(Some(format!("{:.1}{}", scaled, units[index])))
     }
 
     /// Find an occurrence form a source
@@ -225,6 +400,53 @@ impl FunctionMacroMap {
         }
     }
 
+    /// Check if a source contains a literal text
+    ///
+    /// # Usage
+    ///
+    /// $has(needle,haystack)
+    pub(crate) fn has_substring(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 2) {
+            let needle = &args[0];
+            let source = &args[1];
+            Ok(Some(source.contains(needle.as_str()).to_string()))
+        } else {
+            Err(RadError::InvalidArgument(
+                "has requires two arguments".to_owned(),
+            ))
+        }
+    }
+
+    /// Check if a source starts with a text
+    ///
+    /// # Usage
+    ///
+    /// $sw(prefix,source)
+    pub(crate) fn starts_with(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 2) {
+            Ok(Some(args[1].starts_with(args[0].as_str()).to_string()))
+        } else {
+            Err(RadError::InvalidArgument(
+                "sw requires two arguments".to_owned(),
+            ))
+        }
+    }
+
+    /// Check if a source ends with a text
+    ///
+    /// # Usage
+    ///
+    /// $ew(suffix,source)
+    pub(crate) fn ends_with(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 2) {
+            Ok(Some(args[1].ends_with(args[0].as_str()).to_string()))
+        } else {
+            Err(RadError::InvalidArgument(
+                "ew requires two arguments".to_owned(),
+            ))
+        }
+    }
+
     /// Find multiple occurrence form a source
     ///
     /// # Usage
@@ -294,6 +516,52 @@ impl FunctionMacroMap {
         }
     }
 
+    /// Evaluate given expression after substituting runtime macros
+    ///
+    /// Macro bodies are expanded and wrapped with parenthesis. Identifiers that are not runtime
+    /// macros are passed to evalexpr as they are.
+    ///
+    /// # Usage
+    ///
+    /// $evalm(expression)
+    #[cfg(feature = "evalexpr")]
+    pub(crate) fn eval_with_macros(args: &str, p: &mut Processor) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 1) {
+            let formula = &args[0];
+            let mut replaced = String::with_capacity(formula.len());
+            let mut last_end = 0;
+            for ident in IDENT_MATCH.find_iter(formula) {
+                let name = ident.as_str();
+                if p.contains_macro(name, MacroType::Runtime) {
+                    // Expansion doesn't increase a level, thus detect a cycle explicitly
+                    if !p.state.evalm_stack.insert(name.to_owned()) {
+                        return Err(RadError::InvalidExecution(format!(
+                            "Evalm cannot substitute macro \"{}\" recursively",
+                            name
+                        )));
+                    }
+                    let body = p.get_runtime_macro_body(name)?.to_owned();
+                    let body = p.parse_chunk_args(0, "evalm", &body);
+                    p.state.evalm_stack.remove(name);
+                    let body = body?;
+                    replaced.push_str(&formula[last_end..ident.start()]);
+                    // Wrap with parenthesis to preserve operator precedence
+                    replaced.push('(');
+                    replaced.push_str(&body);
+                    replaced.push(')');
+                    last_end = ident.end();
+                }
+            }
+            replaced.push_str(&formula[last_end..]);
+            let result = evalexpr::eval(&replaced)?;
+            Ok(Some(result.to_string()))
+        } else {
+            Err(RadError::InvalidArgument(
+                "Evalm requires an argument".to_owned(),
+            ))
+        }
+    }
+
     /// Pipe in replace evaluation
     ///
     /// # Usage
@@ -389,6 +657,23 @@ impl FunctionMacroMap {
         }
     }
 
+    /// Trim preceding and trailing characters of a given set
+    ///
+    /// # Usage
+    ///
+    /// $trimc(/.,//path/.)
+    pub(crate) fn trim_chars(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 2) {
+            let chars = &args[0];
+            let trimmed = args[1].trim_matches(|c| chars.contains(c));
+            Ok(Some(trimmed.to_string()))
+        } else {
+            Err(RadError::InvalidArgument(
+                "Trimc requires two arguments".to_owned(),
+            ))
+        }
+    }
+
     /// Indent lines
     ///
     /// # Usage
@@ -399,20 +684,11 @@ impl FunctionMacroMap {
     /// )
     pub(crate) fn indent_lines(args: &str, p: &mut Processor) -> RadResult<Option<String>> {
         if let Some(args) = ArgParser::new().args_with_len(args, 2) {
-            let indenter = &args[0];
-            let mut lines = String::new();
-            let mut iter = args[1].lines().peekable();
-            while let Some(line) = iter.next() {
-                if !line.is_empty() {
-                    lines.push_str(indenter);
-                    lines.push_str(line);
-                }
-                // Append newline because String.lines() method cuts off all newlines
-                if iter.peek().is_some() {
-                    lines.push_str(&p.state.newline);
-                }
-            }
-            Ok(Some(lines))
+            Ok(Some(Self::indent_with(
+                &args[0],
+                &args[1],
+                &p.state.newline,
+            )))
         } else {
             Err(RadError::InvalidArgument(
                 "indent requires an argument".to_owned(),
@@ -420,6 +696,55 @@ impl FunctionMacroMap {
         }
     }
 
+    /// Indent lines with a repeated indenter
+    ///
+    /// # Usage
+    ///
+    /// $indentn(2,    , multi
+    /// line
+    /// expression
+    /// )
+    pub(crate) fn indent_lines_repeated(
+        args: &str,
+        p: &mut Processor,
+    ) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 3) {
+            let count = trim!(&args[0]).parse::<usize>().map_err(|_| {
+                RadError::InvalidArgument(format!(
+                    "indentn requires an unsigned integer as count but given \"{}\"",
+                    &args[0]
+                ))
+            })?;
+            let indenter = args[1].repeat(count);
+            Ok(Some(Self::indent_with(
+                &indenter,
+                &args[2],
+                &p.state.newline,
+            )))
+        } else {
+            Err(RadError::InvalidArgument(
+                "indentn requires three arguments".to_owned(),
+            ))
+        }
+    }
+
+    /// Prepend an indenter to non empty lines
+    fn indent_with(indenter: &str, content: &str, newline: &str) -> String {
+        let mut lines = String::new();
+        let mut iter = content.lines().peekable();
+        while let Some(line) = iter.next() {
+            if !line.is_empty() {
+                lines.push_str(indenter);
+                lines.push_str(line);
+            }
+            // Append newline because String.lines() method cuts off all newlines
+            if iter.peek().is_some() {
+                lines.push_str(newline);
+            }
+        }
+        lines
+    }
+
     /// Trim preceding and trailing whitespaces (' ', '\n', '\t', '\r') but for all lines
     ///
     /// # Usage
@@ -518,6 +843,129 @@ impl FunctionMacroMap {
         }
     }
 
+    /// Remove line comments
+    ///
+    /// # Usage
+    ///
+    /// $stripcomment(//,content)
+    pub(crate) fn strip_comment(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 2) {
+            let marker = trim!(&args[0]);
+            if marker.is_empty() {
+                return Err(RadError::InvalidArgument(
+                    "Stripcomment requires a non empty marker".to_owned(),
+                ));
+            }
+            let mut lines = String::with_capacity(args[1].len());
+            for line in Utils::full_lines(args[1].as_bytes()) {
+                let line = line?;
+                let content = line.trim_end_matches(['\r', '\n']);
+                let mut in_string = false;
+                let mut escaped = false;
+                let mut comment_start = None;
+                for (index, ch) in content.char_indices() {
+                    if in_string {
+                        match ch {
+                            _ if escaped => escaped = false,
+                            '\\' => escaped = true,
+                            '"' => in_string = false,
+                            _ => (),
+                        }
+                    } else if ch == '"' {
+                        in_string = true;
+                    } else if content[index..].starts_with(marker.as_ref()) {
+                        comment_start = Some(index);
+                        break;
+                    }
+                }
+                match comment_start {
+                    Some(index) => {
+                        lines.push_str(content[..index].trim_end());
+                        lines.push_str(&line[content.len()..]);
+                    }
+                    None => lines.push_str(&line),
+                }
+            }
+            Ok(Some(lines))
+        } else {
+            Err(RadError::InvalidArgument(
+                "Stripcomment requires two arguments".to_owned(),
+            ))
+        }
+    }
+
+    /// Remove common leading whitespaces
+    ///
+    /// # Usage
+    ///
+    /// $dedent(
+    ///     multi
+    ///     line
+    /// )
+    pub(crate) fn dedent(args: &str, p: &mut Processor) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 1) {
+            let source = &args[0];
+            let mut prefix: Option<&str> = None;
+            for line in source.lines().filter(|line| !line.trim().is_empty()) {
+                let indent = &line[..line.len() - line.trim_start_matches([' ', '\t']).len()];
+                prefix = Some(match prefix {
+                    None => indent,
+                    Some(prefix) => {
+                        let common = prefix
+                            .bytes()
+                            .zip(indent.bytes())
+                            .take_while(|(a, b)| a == b)
+                            .count();
+                        &prefix[..common]
+                    }
+                });
+            }
+            let prefix = prefix.unwrap_or_default();
+
+            let mut lines = String::new();
+            let mut source_iter = source.lines().peekable();
+            while let Some(line) = source_iter.next() {
+                if !line.trim().is_empty() {
+                    lines.push_str(&line[prefix.len()..]);
+                }
+                // Append newline because String.lines() method cuts off all newlines
+                if source_iter.peek().is_some() {
+                    lines.push_str(&p.state.newline);
+                }
+            }
+            Ok(Some(lines))
+        } else {
+            Err(RadError::InvalidArgument(
+                "Dedent requires an argument".to_owned(),
+            ))
+        }
+    }
+
+    /// Trim trailing whitespaces of each line
+    ///
+    /// This preserves leading indentation and original line endings
+    ///
+    /// # Usage
+    ///
+    /// $trimrl(content)
+    pub(crate) fn trimrl(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 1) {
+            let mut lines = String::with_capacity(args[0].len());
+            for line in Utils::full_lines(args[0].as_bytes()) {
+                let line = line?;
+                let content = line.trim_end_matches(['\r', '\n']);
+                let line_ending = &line[content.len()..];
+                lines.push_str(content.trim_end());
+                lines.push_str(line_ending);
+            }
+            Ok(Some(lines))
+        } else {
+            Err(RadError::InvalidArgument(
+                "Trimrl requires an argument".to_owned(),
+            ))
+        }
+    }
+
     /// Removes duplicate newlines whithin given input
     ///
     /// # Usage
@@ -587,6 +1035,35 @@ impl FunctionMacroMap {
         }
     }
 
+    /// Placeholder sentences
+    ///
+    /// # Usage
+    ///
+    /// $lipsums(3)
+    pub(crate) fn lipsum_sentences(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        use itertools::Itertools;
+        if let Some(args) = ArgParser::new().args_with_len(args, 1) {
+            let sentence_count = &args[0];
+            if let Ok(count) = trim!(sentence_count).parse::<usize>() {
+                let sentences = LOREM_SOURCE
+                    .split('.')
+                    .map(str::trim)
+                    .filter(|sentence| !sentence.is_empty())
+                    .cycle()
+                    .take(count)
+                    .map(|sentence| format!("{}.", sentence))
+                    .join(" ");
+                Ok(Some(sentences))
+            } else {
+                Err(RadError::InvalidArgument(format!("Lipsums needs a number bigger or equal to 0 (unsigned integer) but given \"{}\"", sentence_count)))
+            }
+        } else {
+            Err(RadError::InvalidArgument(
+                "Lipsums requires an argument".to_owned(),
+            ))
+        }
+    }
+
     /// Repeat given expression about given amount times
     ///
     /// # Usage
@@ -612,39 +1089,85 @@ impl FunctionMacroMap {
         }
     }
 
-    /// Call system command
-    ///
-    /// This calls via 'CMD \C' in windows platform while unix call is operated without any mediation.
+    /// Repeat given expression with a separator between repetitions
     ///
     /// # Usage
     ///
-    /// $syscmd(system command -a arguments)
-    #[cfg(not(feature = "wasm"))]
-    pub(crate) fn syscmd(args: &str, p: &mut Processor) -> RadResult<Option<String>> {
-        if !Utils::is_granted("syscmd", AuthType::CMD, p)? {
-            return Ok(None);
-        }
-        if let Some(args_content) = ArgParser::new().args_with_len(args, 1) {
-            let source = &args_content[0];
-            let arg_vec = source.split_whitespace().collect::<Vec<&str>>();
-
-            let output = if cfg!(target_os = "windows") {
-                Command::new("cmd")
-                    .arg("/C")
-                    .args(arg_vec)
-                    .output()
-                    .expect("failed to execute process")
-                    .stdout
-            } else {
+    /// $repeatsep(count,sep,text)
+    pub(crate) fn repeat_with_separator(
+        args: &str,
+        _: &mut Processor,
+    ) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 3) {
+            let repeat_count = if let Ok(count) = trim!(&args[0]).parse::<usize>() {
+                count
+            } else {
+                return Err(RadError::InvalidArgument(format!("Repeatsep needs a number bigger or equal to 0 (unsigned integer) but given \"{}\"", &args[0])));
+            };
+            let separator = &args[1];
+            let repeat_object = &args[2];
+            if repeat_count == 0 {
+                return Ok(None);
+            }
+            let mut repeated = String::with_capacity(
+                repeat_object.len() * repeat_count + separator.len() * (repeat_count - 1),
+            );
+            for index in 0..repeat_count {
+                if index > 0 {
+                    repeated.push_str(separator);
+                }
+                repeated.push_str(repeat_object);
+            }
+            Ok(Some(repeated))
+        } else {
+            Err(RadError::InvalidArgument(
+                "Repeatsep requires three arguments".to_owned(),
+            ))
+        }
+    }
+
+    /// Call system command
+    ///
+    /// This calls via 'CMD \C' in windows platform while unix call is operated without any mediation.
+    ///
+    /// # Usage
+    ///
+    /// $syscmd(system command -a arguments)
+    #[cfg(not(feature = "wasm"))]
+    pub(crate) fn syscmd(args: &str, p: &mut Processor) -> RadResult<Option<String>> {
+        if !Utils::is_granted("syscmd", AuthType::CMD, p)? {
+            return Ok(None);
+        }
+        if let Some(args_content) = ArgParser::new().args_with_len(args, 1) {
+            let source = &args_content[0];
+            let arg_vec = source.split_whitespace().collect::<Vec<&str>>();
+
+            let mut command = if cfg!(target_os = "windows") {
+                let mut command = Command::new("cmd");
+                command.arg("/C").args(arg_vec);
+                command
+            } else {
                 let sys_args = if arg_vec.len() > 1 {
                     &arg_vec[1..]
                 } else {
                     &[]
                 };
-                Command::new(arg_vec[0])
-                    .args(sys_args)
+                let mut command = Command::new(arg_vec[0]);
+                command.args(sys_args);
+                command
+            };
+
+            let output = if let Some(timeout) = p.state.shell_timeout {
+                Utils::command_output_with_timeout(&mut command, timeout)?
+            } else {
+                command
                     .output()
-                    .expect("failed to execute process")
+                    .map_err(|_| {
+                        RadError::InvalidExecution(format!(
+                            "Failed to execute command : {:?}",
+                            command
+                        ))
+                    })?
                     .stdout
             };
 
@@ -684,6 +1207,29 @@ impl FunctionMacroMap {
         }
     }
 
+    /// Check if a macro is defined
+    ///
+    /// # Usage
+    ///
+    /// $defined(macro_name)
+    /// $defined(macro_name,runtime)
+    pub(crate) fn is_defined(args: &str, processor: &mut Processor) -> RadResult<Option<String>> {
+        let args = ArgParser::new().args_to_vec(args, ',', SplitVariant::Never);
+        if args.is_empty() || args.len() > 2 {
+            return Err(RadError::InvalidArgument(
+                "defined requires one or two arguments".to_owned(),
+            ));
+        }
+        let name = trim!(&args[0]);
+        let macro_type = match args.get(1) {
+            Some(macro_type) => MacroType::from_str(&trim!(macro_type))?,
+            None => MacroType::Any,
+        };
+        Ok(Some(
+            processor.contains_macro(&name, macro_type).to_string(),
+        ))
+    }
+
     /// Placeholder for define
     pub(crate) fn define_type(_: &str, _: &mut Processor) -> RadResult<Option<String>> {
         Ok(None)
@@ -707,6 +1253,38 @@ impl FunctionMacroMap {
         }
     }
 
+    /// Collapse consecutive runs of a character into a single character
+    ///
+    /// # Usage
+    ///
+    /// $squeezec(/,a//b///c)
+    pub(crate) fn squeeze_char(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 2) {
+            let mut chars = args[0].chars();
+            let target = match (chars.next(), chars.next()) {
+                (Some(ch), None) => ch,
+                _ => {
+                    return Err(RadError::InvalidArgument(format!(
+                        "Squeezec requires a single character but got \"{}\"",
+                        args[0]
+                    )))
+                }
+            };
+            let mut squeezed = String::with_capacity(args[1].len());
+            for ch in args[1].chars() {
+                if ch == target && squeezed.ends_with(target) {
+                    continue;
+                }
+                squeezed.push(ch);
+            }
+            Ok(Some(squeezed))
+        } else {
+            Err(RadError::InvalidArgument(
+                "Squeezec requires two arguments".to_owned(),
+            ))
+        }
+    }
+
     /// Split
     ///
     /// # Usage
@@ -733,6 +1311,34 @@ impl FunctionMacroMap {
         }
     }
 
+    /// Split with a limited number of splits
+    ///
+    /// # Usage
+    ///
+    /// $splitn(1,/,a/b/c)
+    pub(crate) fn splitn(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 3) {
+            let count = trim!(&args[0]).parse::<usize>().map_err(|_| {
+                RadError::InvalidArgument(format!(
+                    "Splitn requires an unsigned integer as a count but got \"{}\"",
+                    &args[0]
+                ))
+            })?;
+            let sep = &args[1];
+            let text = &args[2];
+
+            let result = text
+                .splitn(count.saturating_add(1), sep.as_str())
+                .collect::<Vec<_>>()
+                .join(",");
+            Ok(Some(result))
+        } else {
+            Err(RadError::InvalidArgument(
+                "Splitn requires three arguments".to_owned(),
+            ))
+        }
+    }
+
     /// Split by whitespaces and cut
     ///
     /// # Usage
@@ -827,6 +1433,46 @@ impl FunctionMacroMap {
         }
     }
 
+    /// Split and cut from the end
+    ///
+    /// # Usage
+    ///
+    /// $rcut(.,0,a.b.c)
+    pub(crate) fn split_and_cut_right(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 3) {
+            let sep = &args[0];
+            let split = &mut args[2].rsplit_terminator(sep.as_str());
+            let len = split.clone().count();
+
+            let index = trim!(&args[1]).parse::<isize>().map_err(|_| {
+                RadError::InvalidArgument(format!(
+                    "rcut requires an index to be a integer type but got \"{}\"",
+                    &args[1]
+                ))
+            })?;
+
+            if index >= len as isize || index < -(len as isize) {
+                return Err(RadError::InvalidArgument(format!(
+                    "Index out of range. Given index is \"{}\" but array length is \"{}\"",
+                    index, len
+                )));
+            }
+
+            let final_index = if index < 0 {
+                (len as isize + index) as usize
+            } else {
+                index.max(0) as usize
+            };
+
+            let result = split.nth(final_index).unwrap().to_string();
+            Ok(Some(result))
+        } else {
+            Err(RadError::InvalidArgument(
+                "rcut requires three arguments".to_owned(),
+            ))
+        }
+    }
+
     /// Split whitespaces
     ///
     /// # Usage
@@ -892,6 +1538,68 @@ impl FunctionMacroMap {
         }
     }
 
+    /// Assert empty
+    ///
+    /// # Usage
+    ///
+    /// $assertempty(value)
+    pub(crate) fn assert_empty(args: &str, p: &mut Processor) -> RadResult<Option<String>> {
+        if trim!(args).is_empty() {
+            p.track_assertion(true)?;
+            Ok(None)
+        } else {
+            p.track_assertion(false)?;
+            Err(RadError::AssertFail)
+        }
+    }
+
+    /// Assert not empty
+    ///
+    /// # Usage
+    ///
+    /// $assertfull(value)
+    pub(crate) fn assert_full(args: &str, p: &mut Processor) -> RadResult<Option<String>> {
+        if !trim!(args).is_empty() {
+            p.track_assertion(true)?;
+            Ok(None)
+        } else {
+            p.track_assertion(false)?;
+            Err(RadError::AssertFail)
+        }
+    }
+
+    /// Assert numbers are equal within a tolerance
+    ///
+    /// # Usage
+    ///
+    /// $assertnum(expected,actual,tolerance)
+    pub(crate) fn assert_num(args: &str, p: &mut Processor) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 3) {
+            let mut numbers = [0f64; 3];
+            for (number, arg) in numbers.iter_mut().zip(args.iter()) {
+                let arg = trim!(arg);
+                *number = arg.parse::<f64>().map_err(|_| {
+                    RadError::InvalidArgument(format!(
+                        "Assertnum requires real numbers but got \"{}\"",
+                        arg
+                    ))
+                })?;
+            }
+            let [expected, actual, tolerance] = numbers;
+            if (expected - actual).abs() <= tolerance {
+                p.track_assertion(true)?;
+                Ok(None)
+            } else {
+                p.track_assertion(false)?;
+                Err(RadError::AssertFail)
+            }
+        } else {
+            Err(RadError::InvalidArgument(
+                "Assertnum requires three arguments".to_owned(),
+            ))
+        }
+    }
+
     /// Increment Counter
     ///
     /// # Usage
@@ -983,6 +1691,36 @@ impl FunctionMacroMap {
         }
     }
 
+    /// Zip two halves of an array with a joiner
+    ///
+    /// # Usage
+    ///
+    /// $zip(=,a,b,c,x,y,z)
+    pub(crate) fn zip(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        let args = ArgParser::new().args_to_vec(args, ',', SplitVariant::Never);
+        if args.len() < 2 {
+            return Err(RadError::InvalidArgument(
+                "zip requires at least two arguments".to_owned(),
+            ));
+        }
+        let joiner = &args[0];
+        let items = &args[1..];
+        let (left, right) = items.split_at(items.len() / 2);
+        if left.len() != right.len() {
+            return Err(RadError::InvalidArgument(format!(
+                "zip requires two halves of same length but given \"{}\" and \"{}\"",
+                left.len(),
+                right.len()
+            )));
+        }
+        let zipped = left
+            .iter()
+            .zip(right.iter())
+            .map(|(l, r)| format!("{}{}{}", l, joiner, r))
+            .collect::<Vec<_>>();
+        Ok(Some(zipped.join(",")))
+    }
+
     /// Create a table with given format and csv input
     ///
     /// Available formats are 'github', 'wikitext' and 'html'
@@ -1004,6 +1742,192 @@ impl FunctionMacroMap {
         }
     }
 
+    /// Get a value from ini content
+    ///
+    /// # Usage
+    ///
+    /// $iniget(section,key,content)
+    pub(crate) fn ini_get(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 3) {
+            let section = trim!(&args[0]);
+            let key = trim!(&args[1]);
+            let mut current_section = "";
+            let mut section_found = section.is_empty();
+            for line in args[2].lines().map(str::trim) {
+                if line.is_empty() || line.starts_with([';', '#']) {
+                    continue;
+                }
+                if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                    current_section = name.trim();
+                    section_found |= current_section == section;
+                    continue;
+                }
+                if current_section != section {
+                    continue;
+                }
+                if let Some((name, value)) = line.split_once('=') {
+                    if name.trim() == key {
+                        return Ok(Some(value.trim().to_string()));
+                    }
+                }
+            }
+            if !section_found {
+                return Err(RadError::InvalidArgument(format!(
+                    "Iniget could not find a section \"{}\"",
+                    section
+                )));
+            }
+            Err(RadError::InvalidArgument(format!(
+                "Iniget could not find a key \"{}\" in section \"{}\"",
+                key, section
+            )))
+        } else {
+            Err(RadError::InvalidArgument(
+                "Iniget requires three arguments".to_owned(),
+            ))
+        }
+    }
+
+    /// Swap rows and columns of csv content
+    ///
+    /// # Usage
+    ///
+    /// $transpose(a,b
+    /// c,d)
+    pub(crate) fn transpose(args: &str, p: &mut Processor) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 1) {
+            let rows = args[0]
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .map(|line| line.split(',').collect::<Vec<_>>())
+                .collect::<Vec<_>>();
+            let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+            if p.state.behaviour == ErrorBehaviour::Strict
+                && rows.iter().any(|row| row.len() != width)
+            {
+                return Err(RadError::InvalidArgument(
+                    "Transpose requires rows of equal length".to_owned(),
+                ));
+            }
+            let columns = (0..width)
+                .map(|index| {
+                    rows.iter()
+                        .map(|row| row.get(index).copied().unwrap_or_default())
+                        .collect::<Vec<_>>()
+                        .join(",")
+                })
+                .collect::<Vec<_>>();
+            Ok(Some(columns.join(&p.state.newline)))
+        } else {
+            Err(RadError::InvalidArgument(
+                "Transpose requires an argument".to_owned(),
+            ))
+        }
+    }
+
+    /// Create a table of contents from markdown headings
+    ///
+    /// # Usage
+    ///
+    /// $toc(# Title
+    /// ## Section)
+    pub(crate) fn table_of_contents(args: &str, p: &mut Processor) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 1) {
+            let mut headings = vec![];
+            let mut in_fence = false;
+            for line in args[0].lines() {
+                let line = line.trim_end();
+                if line.trim_start().starts_with("```") {
+                    in_fence = !in_fence;
+                    continue;
+                }
+                if in_fence {
+                    continue;
+                }
+                let level = line.chars().take_while(|&ch| ch == '#').count();
+                let title = &line[level..];
+                if level == 0 || level > 6 || !(title.is_empty() || title.starts_with(' ')) {
+                    continue;
+                }
+                let title = title.trim().trim_end_matches('#').trim_end();
+                if !title.is_empty() {
+                    headings.push((level, title));
+                }
+            }
+
+            let min_level = headings.iter().map(|(level, _)| *level).min().unwrap_or(1);
+            let mut slug_counts: HashMap<String, usize> = HashMap::new();
+            let mut toc = vec![];
+            for (level, title) in headings {
+                let mut slug = slugify_text(title);
+                let count = slug_counts.entry(slug.clone()).or_insert(0);
+                if *count > 0 {
+                    slug = format!("{}-{}", slug, count);
+                }
+                *count += 1;
+                toc.push(format!(
+                    "{}- [{}](#{})",
+                    "  ".repeat(level - min_level),
+                    title,
+                    slug
+                ));
+            }
+            Ok(Some(toc.join(&p.state.newline)))
+        } else {
+            Err(RadError::InvalidArgument(
+                "Toc requires an argument".to_owned(),
+            ))
+        }
+    }
+
+    /// Wrap content in a fenced code block
+    ///
+    /// # Usage
+    ///
+    /// $fence(rust,let a = 1;)
+    pub(crate) fn fence(args: &str, p: &mut Processor) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 2) {
+            let lang = trim!(&args[0]);
+            let content = args[1]
+                .strip_suffix('\n')
+                .map(|content| content.strip_suffix('\r').unwrap_or(content))
+                .unwrap_or(&args[1]);
+            let longest_run = content
+                .split(|ch| ch != '`')
+                .map(str::len)
+                .max()
+                .unwrap_or(0);
+            let fence = "`".repeat((longest_run + 1).max(3));
+            let nl = &p.state.newline;
+            Ok(Some(format!(
+                "{0}{1}{2}{3}{2}{0}",
+                fence, lang, nl, content
+            )))
+        } else {
+            Err(RadError::InvalidArgument(
+                "Fence requires two arguments".to_owned(),
+            ))
+        }
+    }
+
+    /// Convert a github formatted table into csv
+    ///
+    /// # Usage
+    ///
+    /// $untable(|a|b|
+    /// |-|-|
+    /// |1|2|)
+    pub(crate) fn untable(args: &str, p: &mut Processor) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 1) {
+            let result = Formatter::table_to_csv(&args[0], &p.state.newline)?;
+            Ok(Some(result))
+        } else {
+            Err(RadError::InvalidArgument(
+                "Untable requires an argument".to_owned(),
+            ))
+        }
+    }
+
     /// Put value into a temporary stack called pipe
     ///
     /// Piped value can be popped with macro '-'
@@ -1061,6 +1985,45 @@ impl FunctionMacroMap {
         }
     }
 
+    /// Get environment variable with given name or a default value
+    ///
+    /// # Usage
+    ///
+    /// $envd(SHELL,/bin/sh)
+    #[cfg(not(feature = "wasm"))]
+    pub(crate) fn get_env_or_default(args: &str, p: &mut Processor) -> RadResult<Option<String>> {
+        if !Utils::is_granted("envd", AuthType::ENV, p)? {
+            return Ok(None);
+        }
+        if let Some(args) = ArgParser::new().args_with_len(args, 2) {
+            let name = trim!(&args[0]);
+            // Absence is explicitly handled, thus no warning is necessary
+            let value = std::env::var(name.as_ref()).unwrap_or_else(|_| args[1].to_owned());
+            Ok(Some(value))
+        } else {
+            Err(RadError::InvalidArgument(
+                "Envd requires two arguments".to_owned(),
+            ))
+        }
+    }
+
+    /// Get names of environment variables
+    ///
+    /// # Usage
+    ///
+    /// $envlist()
+    #[cfg(not(feature = "wasm"))]
+    pub(crate) fn list_env_names(_: &str, p: &mut Processor) -> RadResult<Option<String>> {
+        if !Utils::is_granted("envlist", AuthType::ENV, p)? {
+            return Ok(None);
+        }
+        let mut names = std::env::vars_os()
+            .map(|(name, _)| name.to_string_lossy().to_string())
+            .collect::<Vec<_>>();
+        names.sort_unstable();
+        Ok(Some(names.join(",")))
+    }
+
     /// Set environment variable with given name
     ///
     /// # Usage
@@ -1357,6 +2320,51 @@ impl FunctionMacroMap {
         Ok(pipe)
     }
 
+    /// Peek pipe value without consuming it
+    ///
+    /// # Usage
+    ///
+    /// $peek()
+    /// $peek(p1)
+    pub(crate) fn peek_pipe(args: &str, processor: &mut Processor) -> RadResult<Option<String>> {
+        let name = trim!(args);
+        let pipe = if name.is_empty() {
+            processor.state.peek_pipe("-")
+        } else {
+            processor.state.peek_pipe(&name)
+        };
+        if pipe.is_none() {
+            if name.is_empty() {
+                processor.log_warning("Empty pipe", WarningType::Sanity)?;
+            } else {
+                processor.log_warning(
+                    &format!("Empty named pipe : \"{}\"", name),
+                    WarningType::Sanity,
+                )?;
+            }
+        }
+        Ok(pipe)
+    }
+
+    /// Get count of pipes
+    ///
+    /// # Usage
+    ///
+    /// $pipelen()
+    pub(crate) fn pipe_length(_: &str, processor: &mut Processor) -> RadResult<Option<String>> {
+        Ok(Some(processor.state.pipe_count().to_string()))
+    }
+
+    /// Clear all pipes
+    ///
+    /// # Usage
+    ///
+    /// $pipeclear()
+    pub(crate) fn pipe_clear(_: &str, processor: &mut Processor) -> RadResult<Option<String>> {
+        processor.state.clear_pipes();
+        Ok(None)
+    }
+
     /// Print left parenthesis
     ///
     /// # Usage
@@ -1389,6 +2397,40 @@ impl FunctionMacroMap {
         Ok(Some(args.chars().count().to_string()))
     }
 
+    /// Get length of a runtime macro's body
+    ///
+    /// # Usage
+    ///
+    /// $bodylen(macro_name)
+    pub(crate) fn body_length(args: &str, processor: &mut Processor) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 1) {
+            let name = trim!(&args[0]);
+            let body = processor.get_runtime_macro_body(&name)?;
+            Ok(Some(body.chars().count().to_string()))
+        } else {
+            Err(RadError::InvalidArgument(
+                "bodylen requires an argument".to_owned(),
+            ))
+        }
+    }
+
+    /// Get raw body of a runtime macro
+    ///
+    /// # Usage
+    ///
+    /// $getbody(macro_name)
+    pub(crate) fn get_body(args: &str, processor: &mut Processor) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 1) {
+            let name = trim!(&args[0]);
+            let body = processor.get_runtime_macro_body(&name)?;
+            Ok(Some(body.to_string()))
+        } else {
+            Err(RadError::InvalidArgument(
+                "getbody requires an argument".to_owned(),
+            ))
+        }
+    }
+
     /// Rename macro rule to other name
     ///
     /// # Usage
@@ -1498,10 +2540,470 @@ impl FunctionMacroMap {
                 _ => unreachable!(),
             };
 
-            Ok(Some(formatted))
+            Ok(Some(formatted))
+        } else {
+            Err(RadError::InvalidArgument(
+                "Align requires four arguments".to_owned(),
+            ))
+        }
+    }
+
+    /// Wrap content in a box frame
+    ///
+    /// # Usage
+    ///
+    /// $box(content)
+    /// $box(ascii,content)
+    pub(crate) fn draw_box(args: &str, p: &mut Processor) -> RadResult<Option<String>> {
+        let mut ap = ArgParser::new();
+        // Leading style is optional, thus content is parsed greedily when it is not given
+        let (style, content) = match ap.args_with_len(args, 2) {
+            Some(mut styled)
+                if ["unicode", "ascii"].contains(&trim!(&styled[0]).to_lowercase().as_str()) =>
+            {
+                let content = styled.pop().unwrap_or_default();
+                (trim!(&styled[0]).to_lowercase(), content)
+            }
+            _ => match ap.args_with_len(args, 1) {
+                Some(mut content) => ("unicode".to_string(), content.remove(0)),
+                None => {
+                    return Err(RadError::InvalidArgument(
+                        "box requires an argument".to_owned(),
+                    ))
+                }
+            },
+        };
+        let [top_left, top_right, bottom_left, bottom_right, horizontal, vertical] =
+            if style == "ascii" {
+                ['+', '+', '+', '+', '-', '|']
+            } else {
+                ['┌', '┐', '└', '┘', '─', '│']
+            };
+        let lines: Vec<&str> = content.lines().collect();
+        let width = lines.iter().map(|line| line.width()).max().unwrap_or(0);
+        let nl = &p.state.newline;
+        let border = horizontal.to_string().repeat(width);
+        let mut framed = format!("{}{}{}{}", top_left, border, top_right, nl);
+        for line in lines {
+            let padding = " ".repeat(width - line.width());
+            framed.push_str(&format!(
+                "{}{}{}{}{}",
+                vertical, line, padding, vertical, nl
+            ));
+        }
+        framed.push_str(&format!("{}{}{}", bottom_left, border, bottom_right));
+        Ok(Some(framed))
+    }
+
+    /// Center lines within a width
+    ///
+    /// # Usage
+    ///
+    /// $centerl(80,lines)
+    pub(crate) fn center_lines(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 2) {
+            let width = trim!(&args[0]).parse::<usize>().map_err(|_| {
+                RadError::InvalidArgument(format!(
+                    "Centerl requires positive integer number as width but got \"{}\"",
+                    &args[0]
+                ))
+            })?;
+            let mut lines = String::with_capacity(args[1].len());
+            for line in Utils::full_lines(args[1].as_bytes()) {
+                let line = line?;
+                let content = line.trim_end_matches(['\r', '\n']);
+                let length = content.width();
+                if length != 0 && length < width {
+                    let space_count = width - length;
+                    lines.push_str(&" ".repeat(space_count - space_count / 2));
+                }
+                lines.push_str(&line);
+            }
+            Ok(Some(lines))
+        } else {
+            Err(RadError::InvalidArgument(
+                "Centerl requires two arguments".to_owned(),
+            ))
+        }
+    }
+
+    /// Pad a number with leading zeros
+    ///
+    /// Sign is kept in front of zeros and wider values are returned unchanged
+    ///
+    /// # Usage
+    ///
+    /// $zfill(5,42)
+    pub(crate) fn zero_fill(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 2) {
+            let width = trim!(&args[0]).parse::<usize>().map_err(|_| {
+                RadError::InvalidArgument(format!(
+                    "Zfill requires positive integer number as width but got \"{}\"",
+                    &args[0]
+                ))
+            })?;
+            let text = trim!(&args[1]);
+
+            let text_length = text.chars().count();
+            if width <= text_length {
+                return Ok(Some(text.to_string()));
+            }
+
+            let (sign, digits) = match text.strip_prefix(['-', '+']) {
+                Some(digits) => (&text[..1], digits),
+                None => ("", text.as_ref()),
+            };
+            Ok(Some(format!(
+                "{}{}{}",
+                sign,
+                "0".repeat(width - text_length),
+                digits
+            )))
+        } else {
+            Err(RadError::InvalidArgument(
+                "Zfill requires two arguments".to_owned(),
+            ))
+        }
+    }
+
+    /// Group digits of a number with a separator
+    ///
+    /// # Usage
+    ///
+    /// $numfmt(1234567,.)
+    pub(crate) fn format_number(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        let args = ArgParser::new().args_to_vec(args, ',', SplitVariant::Never);
+        if args.is_empty() || args.len() > 2 {
+            return Err(RadError::InvalidArgument(
+                "Numfmt requires one or two arguments".to_owned(),
+            ));
+        }
+        let number = trim!(&args[0]);
+        let separator = match args.get(1) {
+            Some(sep) => {
+                let sep = trim!(sep);
+                let mut chars = sep.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(ch), None) => ch,
+                    _ => {
+                        return Err(RadError::InvalidArgument(format!(
+                            "Numfmt requires a single character as separator but given \"{}\"",
+                            sep
+                        )))
+                    }
+                }
+            }
+            None => ',',
+        };
+
+        let (sign, unsigned) = match number.strip_prefix(['-', '+']) {
+            Some(unsigned) => (&number[..1], unsigned),
+            None => ("", number.as_ref()),
+        };
+        let (integer, fraction) = match unsigned.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (unsigned, None),
+        };
+        let is_digits = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_digit());
+        if !is_digits(integer) || !fraction.map(is_digits).unwrap_or(true) {
+            return Err(RadError::InvalidArgument(format!(
+                "Numfmt requires a number but given \"{}\"",
+                number
+            )));
+        }
+
+        let mut formatted = String::from(sign);
+        for (index, digit) in integer.chars().enumerate() {
+            if index != 0 && (integer.len() - index) % 3 == 0 {
+                formatted.push(separator);
+            }
+            formatted.push(digit);
+        }
+        if let Some(fraction) = fraction {
+            formatted.push('.');
+            formatted.push_str(fraction);
+        }
+        Ok(Some(formatted))
+    }
+
+    /// Append an english ordinal suffix to an integer
+    ///
+    /// # Usage
+    ///
+    /// $ordinal(21)
+    pub(crate) fn ordinal(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 1) {
+            let number = trim!(&args[0]).parse::<isize>().map_err(|_| {
+                RadError::InvalidArgument(format!(
+                    "Ordinal requires an integer but given \"{}\"",
+                    &args[0]
+                ))
+            })?;
+            let abs = number.unsigned_abs();
+            let suffix = match (abs % 10, abs % 100) {
+                (_, 11..=13) => "th",
+                (1, _) => "st",
+                (2, _) => "nd",
+                (3, _) => "rd",
+                _ => "th",
+            };
+            Ok(Some(format!("{}{}", number, suffix)))
+        } else {
+            Err(RadError::InvalidArgument(
+                "Ordinal requires an argument".to_owned(),
+            ))
+        }
+    }
+
+    /// Percent encode text
+    ///
+    /// # Usage
+    ///
+    /// $urlenc(hello world)
+    pub(crate) fn url_encode(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        use std::fmt::Write;
+        if let Some(args) = ArgParser::new().args_with_len(args, 1) {
+            let mut encoded = String::with_capacity(args[0].len());
+            for byte in args[0].bytes() {
+                if byte.is_ascii_alphanumeric() || b"-._~".contains(&byte) {
+                    encoded.push(byte as char);
+                } else {
+                    write!(encoded, "%{:02X}", byte)?;
+                }
+            }
+            Ok(Some(encoded))
+        } else {
+            Err(RadError::InvalidArgument(
+                "Urlenc requires an argument".to_owned(),
+            ))
+        }
+    }
+
+    /// Decode percent encoded text
+    ///
+    /// # Usage
+    ///
+    /// $urldec(hello%20world)
+    pub(crate) fn url_decode(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 1) {
+            let source = args[0].as_bytes();
+            let mut bytes = Vec::with_capacity(source.len());
+            let mut index = 0;
+            while index < source.len() {
+                if source[index] == b'%' {
+                    let byte = source
+                        .get(index + 1..index + 3)
+                        .filter(|hex| hex.iter().all(u8::is_ascii_hexdigit))
+                        .and_then(|hex| std::str::from_utf8(hex).ok())
+                        .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                        .ok_or_else(|| {
+                            RadError::InvalidArgument(format!(
+                                "Urldec got an invalid percent sequence at byte {} in \"{}\"",
+                                index, &args[0]
+                            ))
+                        })?;
+                    bytes.push(byte);
+                    index += 3;
+                } else {
+                    bytes.push(source[index]);
+                    index += 1;
+                }
+            }
+            let decoded = String::from_utf8(bytes).map_err(|_| {
+                RadError::InvalidArgument(format!(
+                    "Decoded content of \"{}\" is not a valid utf8 text",
+                    &args[0]
+                ))
+            })?;
+            Ok(Some(decoded))
+        } else {
+            Err(RadError::InvalidArgument(
+                "Urldec requires an argument".to_owned(),
+            ))
+        }
+    }
+
+    /// Escape html special characters
+    ///
+    /// # Usage
+    ///
+    /// $htmlesc(<b>text</b>)
+    pub(crate) fn html_escape(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 1) {
+            let mut escaped = String::with_capacity(args[0].len());
+            for ch in args[0].chars() {
+                match ch {
+                    '&' => escaped.push_str("&amp;"),
+                    '<' => escaped.push_str("&lt;"),
+                    '>' => escaped.push_str("&gt;"),
+                    '"' => escaped.push_str("&quot;"),
+                    '\'' => escaped.push_str("&apos;"),
+                    _ => escaped.push(ch),
+                }
+            }
+            Ok(Some(escaped))
+        } else {
+            Err(RadError::InvalidArgument(
+                "Htmlesc requires an argument".to_owned(),
+            ))
+        }
+    }
+
+    /// Unescape html entities
+    ///
+    /// # Usage
+    ///
+    /// $htmlunesc(&lt;b&gt;text&lt;/b&gt;)
+    pub(crate) fn html_unescape(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        const ENTITIES: [(&str, char); 6] = [
+            ("&amp;", '&'),
+            ("&lt;", '<'),
+            ("&gt;", '>'),
+            ("&quot;", '"'),
+            ("&apos;", '\''),
+            ("&#39;", '\''),
+        ];
+        if let Some(args) = ArgParser::new().args_with_len(args, 1) {
+            let mut unescaped = String::with_capacity(args[0].len());
+            let mut rest: &str = &args[0];
+            while let Some(index) = rest.find('&') {
+                unescaped.push_str(&rest[..index]);
+                rest = &rest[index..];
+                match ENTITIES.iter().find(|(entity, _)| rest.starts_with(entity)) {
+                    Some((entity, ch)) => {
+                        unescaped.push(*ch);
+                        rest = &rest[entity.len()..];
+                    }
+                    None => {
+                        unescaped.push('&');
+                        rest = &rest[1..];
+                    }
+                }
+            }
+            unescaped.push_str(rest);
+            Ok(Some(unescaped))
+        } else {
+            Err(RadError::InvalidArgument(
+                "Htmlunesc requires an argument".to_owned(),
+            ))
+        }
+    }
+
+    /// Escape text for a json string
+    ///
+    /// # Usage
+    ///
+    /// $jsonesc(text)
+    pub(crate) fn json_escape(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        use std::fmt::Write;
+        if let Some(args) = ArgParser::new().args_with_len(args, 1) {
+            let mut escaped = String::with_capacity(args[0].len());
+            for ch in args[0].chars() {
+                match ch {
+                    '"' => escaped.push_str("\\\""),
+                    '\\' => escaped.push_str("\\\\"),
+                    '\n' => escaped.push_str("\\n"),
+                    '\r' => escaped.push_str("\\r"),
+                    '\t' => escaped.push_str("\\t"),
+                    '\u{08}' => escaped.push_str("\\b"),
+                    '\u{0C}' => escaped.push_str("\\f"),
+                    ch if (ch as u32) < 0x20 => write!(escaped, "\\u{:04x}", ch as u32)?,
+                    _ => escaped.push(ch),
+                }
+            }
+            Ok(Some(escaped))
+        } else {
+            Err(RadError::InvalidArgument(
+                "Jsonesc requires an argument".to_owned(),
+            ))
+        }
+    }
+
+    /// Convert text into a url slug
+    ///
+    /// # Usage
+    ///
+    /// $slugify(Hello, World!)
+    pub(crate) fn slugify(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 1) {
+            Ok(Some(slugify_text(&args[0])))
+        } else {
+            Err(RadError::InvalidArgument(
+                "Slugify requires an argument".to_owned(),
+            ))
+        }
+    }
+
+    /// Get a stable hex digest of content
+    ///
+    /// # Usage
+    ///
+    /// $hash(content)
+    pub(crate) fn hash(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+        const FNV_PRIME: u64 = 0x100000001b3;
+        if let Some(args) = ArgParser::new().args_with_len(args, 1) {
+            let digest = args[0].bytes().fold(FNV_OFFSET, |hash, byte| {
+                (hash ^ byte as u64).wrapping_mul(FNV_PRIME)
+            });
+            Ok(Some(format!("{:016x}", digest)))
+        } else {
+            Err(RadError::InvalidArgument(
+                "Hash requires an argument".to_owned(),
+            ))
+        }
+    }
+
+    /// Convert an integer into a roman numeral
+    ///
+    /// # Usage
+    ///
+    /// $roman(14)
+    pub(crate) fn to_roman(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 1) {
+            let number = trim!(&args[0]);
+            match number.parse::<usize>() {
+                Ok(number @ 1..=3999) => Ok(Some(int_to_roman(number))),
+                _ => Err(RadError::InvalidArgument(format!(
+                    "Roman requires an integer between 1 and 3999 but given \"{}\"",
+                    number
+                ))),
+            }
         } else {
             Err(RadError::InvalidArgument(
-                "Align requires four arguments".to_owned(),
+                "Roman requires an argument".to_owned(),
+            ))
+        }
+    }
+
+    /// Convert a roman numeral into an integer
+    ///
+    /// # Usage
+    ///
+    /// $unroman(XIV)
+    pub(crate) fn from_roman(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 1) {
+            let numeral = trim!(&args[0]).to_uppercase();
+            let mut number = 0;
+            let mut rest = numeral.as_str();
+            for (value, symbol) in ROMAN_NUMERALS {
+                while let Some(stripped) = rest.strip_prefix(symbol) {
+                    number += value;
+                    rest = stripped;
+                }
+            }
+            // Reject non canonical forms such as IIII or IC
+            if !rest.is_empty() || !(1..=3999).contains(&number) || int_to_roman(number) != numeral
+            {
+                return Err(RadError::InvalidArgument(format!(
+                    "Unroman requires a valid roman numeral but given \"{}\"",
+                    numeral
+                )));
+            }
+            Ok(Some(number.to_string()))
+        } else {
+            Err(RadError::InvalidArgument(
+                "Unroman requires an argument".to_owned(),
             ))
         }
     }
@@ -1706,9 +3208,12 @@ impl FunctionMacroMap {
 
     /// Save content to a file
     ///
+    /// Content is appended to an existing file if truncate is false
+    ///
     /// # Usage
     ///
     /// $fileout(file_name,true,Content)
+    /// $fileout(file_name,false,Content)
     #[cfg(not(feature = "wasm"))]
     pub(crate) fn file_out(args: &str, p: &mut Processor) -> RadResult<Option<String>> {
         if !Utils::is_granted("fileout", AuthType::FOUT, p)? {
@@ -1739,7 +3244,7 @@ impl FunctionMacroMap {
                         .open(path)?
                 } else {
                     if !path.exists() {
-                        return Err(RadError::InvalidArgument(format!("Failed to write \"{}\". Fileout without truncate option needs exsiting non-directory file",path.display())));
+                        return Err(RadError::InvalidArgument(format!("Failed to write \"{}\". Fileout without truncate option needs existing non-directory file",path.display())));
                     }
 
                     OpenOptions::new().append(true).open(path)?
@@ -1759,6 +3264,101 @@ impl FunctionMacroMap {
         }
     }
 
+    /// Copy a file
+    ///
+    /// # Usage
+    ///
+    /// $copyfile(source,destination)
+    #[cfg(not(feature = "wasm"))]
+    pub(crate) fn copy_file(args: &str, p: &mut Processor) -> RadResult<Option<String>> {
+        if !Utils::is_granted("copyfile", AuthType::FIN, p)?
+            || !Utils::is_granted("copyfile", AuthType::FOUT, p)?
+        {
+            return Ok(None);
+        }
+        let (source, destination) = Self::get_transfer_paths("copyfile", args, p)?;
+        std::fs::copy(source, destination)?;
+        Ok(None)
+    }
+
+    /// Move a file
+    ///
+    /// # Usage
+    ///
+    /// $movefile(source,destination)
+    #[cfg(not(feature = "wasm"))]
+    pub(crate) fn move_file(args: &str, p: &mut Processor) -> RadResult<Option<String>> {
+        if !Utils::is_granted("movefile", AuthType::FIN, p)?
+            || !Utils::is_granted("movefile", AuthType::FOUT, p)?
+        {
+            return Ok(None);
+        }
+        let (source, destination) = Self::get_transfer_paths("movefile", args, p)?;
+        // Rename fails across devices, fall back to copy and remove
+        if std::fs::rename(&source, &destination).is_err() {
+            std::fs::copy(&source, &destination)?;
+            std::fs::remove_file(&source)?;
+        }
+        Ok(None)
+    }
+
+    /// Get sanity checked source and destination paths for file transfer
+    #[cfg(not(feature = "wasm"))]
+    fn get_transfer_paths(
+        macro_name: &str,
+        args: &str,
+        p: &Processor,
+    ) -> RadResult<(PathBuf, PathBuf)> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 2) {
+            let source = PathBuf::from(trim!(&args[0]).as_ref());
+            let destination = PathBuf::from(trim!(&args[1]).as_ref());
+            if !source.is_file() {
+                return Err(RadError::InvalidArgument(format!(
+                    "{} requires a real file to read from but \"{}\" doesn't exist",
+                    macro_name,
+                    source.display()
+                )));
+            }
+            Utils::check_file_sanity(p, &source.canonicalize()?)?;
+            if destination.exists() {
+                Utils::check_file_sanity(p, &destination.canonicalize()?)?;
+            }
+            Ok((source, destination))
+        } else {
+            Err(RadError::InvalidArgument(format!(
+                "{} requires two arguments",
+                macro_name
+            )))
+        }
+    }
+
+    /// Create a directory recursively
+    ///
+    /// # Usage
+    ///
+    /// $mkdir(path/to/dir)
+    #[cfg(not(feature = "wasm"))]
+    pub(crate) fn make_directory(args: &str, p: &mut Processor) -> RadResult<Option<String>> {
+        if !Utils::is_granted("mkdir", AuthType::FOUT, p)? {
+            return Ok(None);
+        }
+        if let Some(args) = ArgParser::new().args_with_len(args, 1) {
+            let path = PathBuf::from(trim!(&args[0]).as_ref());
+            std::fs::create_dir_all(&path).map_err(|err| {
+                RadError::InvalidExecution(format!(
+                    "Failed to create a directory \"{}\" : {}",
+                    path.display(),
+                    err
+                ))
+            })?;
+            Ok(None)
+        } else {
+            Err(RadError::InvalidArgument(
+                "Mkdir requires an argument".to_owned(),
+            ))
+        }
+    }
+
     /// Get head of given text
     ///
     /// # Usage
@@ -1886,23 +3486,12 @@ impl FunctionMacroMap {
     /// # Usage
     ///
     /// $sort(asec,1,2,3,4,5)
+    /// $sort(num,10,2,1)
     pub(crate) fn sort_array(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
         if let Some(args) = ArgParser::new().args_with_len(args, 2) {
-            let order_type = trim!(&args[0]);
+            let order_type = OrderType::from_str(trim!(&args[0]).as_ref())?;
             let content = &mut args[1].split(',').collect::<Vec<&str>>();
-            match order_type.to_lowercase().as_str() {
-                "asec" => content.sort_unstable(),
-                "desc" => {
-                    content.sort_unstable();
-                    content.reverse()
-                }
-                _ => {
-                    return Err(RadError::InvalidArgument(format!(
-                        "Sort requires either asec or desc but given \"{}\"",
-                        order_type
-                    )))
-                }
-            }
+            order_type.sort(content)?;
 
             Ok(Some(content.join(",")))
         } else {
@@ -1919,21 +3508,9 @@ impl FunctionMacroMap {
     /// $sortl(asec,Content)
     pub(crate) fn sort_lines(args: &str, p: &mut Processor) -> RadResult<Option<String>> {
         if let Some(args) = ArgParser::new().args_with_len(args, 2) {
-            let order_type = trim!(&args[0]);
+            let order_type = OrderType::from_str(trim!(&args[0]).as_ref())?;
             let content = &mut args[1].lines().collect::<Vec<&str>>();
-            match order_type.to_lowercase().as_str() {
-                "asec" => content.sort_unstable(),
-                "desc" => {
-                    content.sort_unstable();
-                    content.reverse()
-                }
-                _ => {
-                    return Err(RadError::InvalidArgument(format!(
-                        "Sortl requires either asec or desc but given \"{}\"",
-                        order_type
-                    )))
-                }
-            }
+            order_type.sort(content)?;
 
             Ok(Some(content.join(&p.state.newline)))
         } else {
@@ -1943,6 +3520,96 @@ impl FunctionMacroMap {
         }
     }
 
+    /// Remove duplicate items from an array
+    ///
+    /// Only consecutive duplicates are removed unless a leading "true" is given. A leading "true" or
+    /// "false" is always consumed as a flag, thus give an explicit flag when data starts with one.
+    ///
+    /// # Usage
+    ///
+    /// $uniq(a,a,b,c,c)
+    /// $uniq(true,a,b,a)
+    pub(crate) fn unique_array(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        let items = ArgParser::new().args_to_vec(args, ',', SplitVariant::Never);
+        let (global, items) = match items.split_first() {
+            Some((flag, rest)) if !rest.is_empty() => match Self::parse_global_flag(flag) {
+                Some(global) => (global, rest),
+                None => (false, &items[..]),
+            },
+            _ => (false, &items[..]),
+        };
+
+        let mut seen = HashSet::new();
+        let mut previous: Option<&str> = None;
+        let mut result = vec![];
+        for item in items {
+            let duplicate = if global {
+                !seen.insert(item.as_str())
+            } else {
+                previous == Some(item.as_str())
+            };
+            previous.replace(item);
+            if !duplicate {
+                result.push(item.as_str());
+            }
+        }
+        Ok(Some(result.join(",")))
+    }
+
+    /// Remove duplicate lines
+    ///
+    /// Only consecutive duplicates are removed unless a leading "true" is given.
+    ///
+    /// # Usage
+    ///
+    /// $uniql(Content)
+    /// $uniql(true,Content)
+    pub(crate) fn unique_lines(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        // Every chunk is parsed, thus content is processed the same way whether a flag is given
+        // or not. Chunks are joined back with the delimiter consumed by the split.
+        let parsed = ArgParser::new().args_to_vec(args, ',', SplitVariant::Never);
+        let (global, content) = match parsed.split_first() {
+            Some((flag, rest)) if !rest.is_empty() => match Self::parse_global_flag(flag) {
+                Some(global) => (global, rest.join(",")),
+                None => (false, parsed.join(",")),
+            },
+            _ => (false, parsed.join(",")),
+        };
+
+        let mut seen = HashSet::new();
+        let mut previous: Option<String> = None;
+        let mut result = String::new();
+        for line in Utils::full_lines(content.as_bytes()) {
+            let line = line?;
+            let key = line.trim_end_matches(['\r', '\n']).to_owned();
+            let duplicate = if global {
+                !seen.insert(key.clone())
+            } else {
+                previous.as_ref() == Some(&key)
+            };
+            previous.replace(key);
+            if !duplicate {
+                result.push_str(&line);
+            }
+        }
+
+        // Don't append a newline that was not in the original content
+        if !content.ends_with('\n') {
+            let trimmed_len = result.trim_end_matches(['\r', '\n']).len();
+            result.truncate(trimmed_len);
+        }
+        Ok(Some(result))
+    }
+
+    /// Check if given text is a leading "true" or "false" flag
+    fn parse_global_flag(text: &str) -> Option<bool> {
+        match trim!(text).as_ref() {
+            "true" => Some(true),
+            "false" => Some(false),
+            _ => None,
+        }
+    }
+
     // [1 2 3]
     //  0 1 2
     //  -3-2-1
@@ -1994,6 +3661,50 @@ impl FunctionMacroMap {
         }
     }
 
+    /// Enumerate array
+    ///
+    /// # Usage
+    ///
+    /// $enumerate(a,b,c)
+    pub(crate) fn enumerate(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        Ok(Some(Self::enumerate_array(args, 0, ":")))
+    }
+
+    /// Enumerate array with a start index and a separator
+    ///
+    /// # Usage
+    ///
+    /// $enumeratef(1,.,a,b,c)
+    pub(crate) fn enumerate_with_format(
+        args: &str,
+        _: &mut Processor,
+    ) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 3) {
+            let start = trim!(&args[0]).parse::<isize>().map_err(|_| {
+                RadError::InvalidArgument(format!(
+                    "enumeratef requires an integer as start index but given \"{}\"",
+                    &args[0]
+                ))
+            })?;
+            Ok(Some(Self::enumerate_array(&args[2], start, &args[1])))
+        } else {
+            Err(RadError::InvalidArgument(
+                "enumeratef requires three arguments".to_owned(),
+            ))
+        }
+    }
+
+    /// Prefix array elements with indices
+    fn enumerate_array(array: &str, start: isize, separator: &str) -> String {
+        ArgParser::new()
+            .args_to_vec(array, ',', SplitVariant::Never)
+            .iter()
+            .enumerate()
+            .map(|(index, value)| format!("{}{}{}", start + index as isize, separator, value))
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
     /// Index lines
     ///
     /// # Usage
@@ -2311,6 +4022,45 @@ impl FunctionMacroMap {
         }
     }
 
+    /// Get every n-th item of an array from a start index
+    ///
+    /// # Usage
+    ///
+    /// $stepa(0,2,a,b,c,d,e,f)
+    pub(crate) fn step_array(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        let args = ArgParser::new().args_to_vec(args, ',', SplitVariant::Never);
+        if args.len() < 3 {
+            return Err(RadError::InvalidArgument(
+                "stepa requires three arguments".to_owned(),
+            ));
+        }
+        let start = trim!(&args[0]).parse::<usize>().map_err(|_| {
+            RadError::InvalidArgument(format!(
+                "stepa's start should be a positive integer but given \"{}\"",
+                args[0]
+            ))
+        })?;
+        let step = trim!(&args[1]).parse::<usize>().map_err(|_| {
+            RadError::InvalidArgument(format!(
+                "stepa's step should be a positive integer but given \"{}\"",
+                args[1]
+            ))
+        })?;
+        if step < 1 {
+            return Err(RadError::InvalidArgument(
+                "stepa's step should be bigger or equal to 1".to_owned(),
+            ));
+        }
+
+        let stepped = args[2..]
+            .iter()
+            .skip(start)
+            .step_by(step)
+            .map(|s| s.as_str())
+            .collect::<Vec<_>>();
+        Ok(Some(stepped.join(",")))
+    }
+
     /// Fold array
     ///
     /// # Usage
@@ -2413,6 +4163,38 @@ impl FunctionMacroMap {
         }
     }
 
+    /// Capture a named group from every match
+    ///
+    /// # Usage
+    ///
+    /// $grepg(expr,group_name,Content)
+    pub(crate) fn grep_group(args: &str, p: &mut Processor) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 3) {
+            let expr = &args[0];
+            let group_name = trim!(&args[1]);
+            let nl = p.state.newline.clone();
+            let reg = p.try_get_or_insert_regex(expr)?;
+            if !reg.capture_names().flatten().any(|n| n == group_name) {
+                return Err(RadError::InvalidArgument(format!(
+                    "grepg cannot find a named group \"{}\" from expression \"{}\"",
+                    group_name, expr
+                )));
+            }
+            let acc = reg
+                .captures_iter(&args[2])
+                .fold(String::new(), |mut acc, x| {
+                    acc.push_str(x.name(&group_name).map_or("", |s| s.as_str()));
+                    acc.push_str(&nl);
+                    acc
+                });
+            Ok(acc.strip_suffix(&nl).map(|s| s.to_owned()))
+        } else {
+            Err(RadError::InvalidArgument(
+                "grepg requires three arguments".to_owned(),
+            ))
+        }
+    }
+
     /// Grep items from array
     ///
     /// # Usage
@@ -2466,6 +4248,59 @@ impl FunctionMacroMap {
         }
     }
 
+    /// Grepl but for unmatched lines
+    ///
+    /// # Usage
+    ///
+    /// $grepv(expr,Lines)
+    pub(crate) fn grep_lines_inverted(args: &str, p: &mut Processor) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 2) {
+            let expr = &args[0];
+            let nl = p.state.newline.clone();
+            let reg = p.try_get_or_insert_regex(expr)?;
+            let content = args[1].lines();
+            let grepped = content
+                .filter(|l| !reg.is_match(l))
+                .fold(String::new(), |mut acc, l| {
+                    acc.push_str(l);
+                    acc.push_str(&nl);
+                    acc
+                });
+            Ok(grepped.strip_suffix(&nl).map(|s| s.to_owned()))
+        } else {
+            Err(RadError::InvalidArgument(
+                "grepv requires two arguments".to_owned(),
+            ))
+        }
+    }
+
+    /// Grepl with line numbers
+    ///
+    /// # Usage
+    ///
+    /// $grepn(expr,Lines)
+    pub(crate) fn grep_lines_numbered(args: &str, p: &mut Processor) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 2) {
+            let expr = &args[0];
+            let nl = p.state.newline.clone();
+            let reg = p.try_get_or_insert_regex(expr)?;
+            let content = args[1].lines();
+            let grepped = content.enumerate().filter(|(_, l)| reg.is_match(l)).fold(
+                String::new(),
+                |mut acc, (index, l)| {
+                    acc.push_str(&format!("{}:{}", index + 1, l));
+                    acc.push_str(&nl);
+                    acc
+                },
+            );
+            Ok(grepped.strip_suffix(&nl).map(|s| s.to_owned()))
+        } else {
+            Err(RadError::InvalidArgument(
+                "grepn requires two arguments".to_owned(),
+            ))
+        }
+    }
+
     /// Grepf
     ///
     /// # Usage
@@ -2594,17 +4429,92 @@ impl FunctionMacroMap {
     ///
     /// # Usage
     ///
-    /// $countl(CONTENT goes here)
-    pub(crate) fn count_lines(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
-        if let Some(args) = ArgParser::new().args_with_len(args, 1) {
-            if args[0].is_empty() {
-                return Ok(Some("0".to_string()));
+    /// $countl(CONTENT goes here)
+    pub(crate) fn count_lines(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 1) {
+            if args[0].is_empty() {
+                return Ok(Some("0".to_string()));
+            }
+            let line_count = args[0].split('\n').count();
+            Ok(Some(line_count.to_string()))
+        } else {
+            Err(RadError::InvalidArgument(
+                "countl requires an argument".to_owned(),
+            ))
+        }
+    }
+
+    /// Get frequencies of array elements
+    ///
+    /// # Usage
+    ///
+    /// $freq(a,b,a)
+    pub(crate) fn frequency(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        let values = ArgParser::new().args_to_vec(args, ',', SplitVariant::Never);
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        for value in &values {
+            *counts.entry(value.as_str()).or_insert(0) += 1;
+        }
+        let mut counts: Vec<(&str, usize)> = counts.into_iter().collect();
+        counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        let result = counts
+            .iter()
+            .map(|(value, count)| format!("{}:{}", value, count))
+            .collect::<Vec<_>>()
+            .join(",");
+        Ok(Some(result))
+    }
+
+    /// Count lines, words and characters
+    ///
+    /// # Usage
+    ///
+    /// $wc(CONTENT goes here)
+    pub(crate) fn word_count(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 1) {
+            let content = &args[0];
+            // A trailing newline doesn't start a new line as unix wc
+            let line_count = if content.is_empty() {
+                0
+            } else {
+                Utils::count_sentences(content.strip_suffix('\n').unwrap_or(content))
+            };
+            Ok(Some(format!(
+                "{} {} {}",
+                line_count,
+                content.split_whitespace().count(),
+                content.chars().count()
+            )))
+        } else {
+            Err(RadError::InvalidArgument(
+                "wc requires an argument".to_owned(),
+            ))
+        }
+    }
+
+    /// Count regex matches
+    ///
+    /// This counts every match in a whole content regardless of lines
+    ///
+    /// # Usage
+    ///
+    /// $countm(expr,CONTENT goes here)
+    pub(crate) fn count_matches(args: &str, p: &mut Processor) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 2) {
+            let match_expr = &args[0];
+            let content = &args[1];
+
+            if match_expr.is_empty() {
+                return Err(RadError::InvalidArgument(
+                    "Regex expression cannot be an empty string".to_string(),
+                ));
             }
-            let line_count = args[0].split('\n').count();
-            Ok(Some(line_count.to_string()))
+
+            let reg = p.try_get_or_insert_regex(match_expr)?;
+            Ok(Some(reg.find_iter(content).count().to_string()))
         } else {
             Err(RadError::InvalidArgument(
-                "countl requires an argument".to_owned(),
+                "countm requires two arguments".to_owned(),
             ))
         }
     }
@@ -2885,6 +4795,87 @@ impl FunctionMacroMap {
         }
     }
 
+    /// Convert text into camel case
+    ///
+    /// # Usage
+    ///
+    /// $camel(hello world)
+    pub(crate) fn camel_case(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 1) {
+            let words = split_case_words(&trim!(&args[0]));
+            Ok(Some(
+                words
+                    .iter()
+                    .enumerate()
+                    .map(|(index, word)| {
+                        if index == 0 {
+                            word.to_owned()
+                        } else {
+                            capitalize_word(word)
+                        }
+                    })
+                    .collect::<String>(),
+            ))
+        } else {
+            Err(RadError::InvalidArgument(
+                "Camel requires an argument".to_owned(),
+            ))
+        }
+    }
+
+    /// Convert text into pascal case
+    ///
+    /// # Usage
+    ///
+    /// $pascal(hello world)
+    pub(crate) fn pascal_case(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 1) {
+            let words = split_case_words(&trim!(&args[0]));
+            Ok(Some(
+                words
+                    .iter()
+                    .map(|word| capitalize_word(word))
+                    .collect::<String>(),
+            ))
+        } else {
+            Err(RadError::InvalidArgument(
+                "Pascal requires an argument".to_owned(),
+            ))
+        }
+    }
+
+    /// Convert text into snake case
+    ///
+    /// # Usage
+    ///
+    /// $snake(hello world)
+    pub(crate) fn snake_case(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 1) {
+            let words = split_case_words(&trim!(&args[0]));
+            Ok(Some(words.join("_")))
+        } else {
+            Err(RadError::InvalidArgument(
+                "Snake requires an argument".to_owned(),
+            ))
+        }
+    }
+
+    /// Convert text into kebab case
+    ///
+    /// # Usage
+    ///
+    /// $kebab(hello world)
+    pub(crate) fn kebab_case(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 1) {
+            let words = split_case_words(&trim!(&args[0]));
+            Ok(Some(words.join("-")))
+        } else {
+            Err(RadError::InvalidArgument(
+                "Kebab requires an argument".to_owned(),
+            ))
+        }
+    }
+
     /// Lower text
     ///
     /// # Usage
@@ -3027,6 +5018,165 @@ impl FunctionMacroMap {
         Ok(None)
     }
 
+    /// Get a cartesian product of two arrays
+    ///
+    /// # Usage
+    ///
+    /// $cartesian(a,b;1,2;-)
+    pub(crate) fn cartesian_product(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        let lists = ArgParser::new().args_to_vec(args, ';', SplitVariant::Never);
+        if lists.len() != 2 && lists.len() != 3 {
+            return Err(RadError::InvalidArgument(
+                "cartesian requires two arrays and an optional join string separated by semicolons"
+                    .to_owned(),
+            ));
+        }
+        let join = lists.get(2).map(String::as_str).unwrap_or_default();
+        let mut product = vec![];
+        for left in lists[0].split(',') {
+            for right in lists[1].split(',') {
+                product.push(format!("{}{}{}", left, join, right));
+            }
+        }
+        Ok(Some(product.join(",")))
+    }
+
+    /// Create a sequence of characters
+    ///
+    /// # Usage
+    ///
+    /// $charseq(a,z)
+    pub(crate) fn char_sequence(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 2) {
+            let parse = |value: &str| {
+                let value = trim!(value);
+                let mut chars = value.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(ch), None) => Ok(ch),
+                    _ => Err(RadError::InvalidArgument(format!(
+                        "charseq requires a single character but given \"{}\"",
+                        value
+                    ))),
+                }
+            };
+            let (start, end) = (parse(&args[0])?, parse(&args[1])?);
+            if start > end {
+                return Err(RadError::InvalidArgument(format!(
+                    "charseq requires start to be less or equal to end but given \"{}\" and \"{}\"",
+                    start, end
+                )));
+            }
+            let sequence = (start..=end).map(String::from).collect::<Vec<_>>();
+            Ok(Some(sequence.join(",")))
+        } else {
+            Err(RadError::InvalidArgument(
+                "charseq requires two arguments".to_owned(),
+            ))
+        }
+    }
+
+    /// Create a sequence of integers
+    ///
+    /// # Usage
+    ///
+    /// $seq(1,10,2)
+    pub(crate) fn sequence(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        let args = ArgParser::new().args_to_vec(args, ',', SplitVariant::Never);
+        if args.len() < 2 || args.len() > 3 {
+            return Err(RadError::InvalidArgument(
+                "seq requires two or three arguments".to_owned(),
+            ));
+        }
+        let parse = |value: &str| {
+            trim!(value).parse::<isize>().map_err(|_| {
+                RadError::InvalidArgument(format!(
+                    "seq requires an integer but given \"{}\"",
+                    value
+                ))
+            })
+        };
+        let (start, end) = (parse(&args[0])?, parse(&args[1])?);
+        let step = match args.get(2) {
+            Some(step) => parse(step)?,
+            None if start > end => -1,
+            None => 1,
+        };
+        if step == 0 {
+            return Err(RadError::InvalidArgument(
+                "seq requires a non zero step".to_owned(),
+            ));
+        }
+        if (start < end && step < 0) || (start > end && step > 0) {
+            return Err(RadError::InvalidArgument(format!(
+                "seq cannot reach \"{}\" from \"{}\" with a step \"{}\"",
+                end, start, step
+            )));
+        }
+        let mut sequence = vec![];
+        let mut current = start;
+        while (step > 0 && current <= end) || (step < 0 && current >= end) {
+            sequence.push(current.to_string());
+            match current.checked_add(step) {
+                Some(next) => current = next,
+                None => break,
+            }
+        }
+        Ok(Some(sequence.join(",")))
+    }
+
+    /// Get sum of numbers
+    ///
+    /// # Usage
+    ///
+    /// $sum(1,2,3)
+    pub(crate) fn sum(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        let numbers = Self::parse_number_array("sum", args)?;
+        Ok(Some(numbers.iter().sum::<f64>().to_string()))
+    }
+
+    /// Get average of numbers
+    ///
+    /// # Usage
+    ///
+    /// $avg(1,2,3)
+    pub(crate) fn average(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        let numbers = Self::parse_number_array("avg", args)?;
+        let average = numbers.iter().sum::<f64>() / numbers.len() as f64;
+        Ok(Some(average.to_string()))
+    }
+
+    /// Get product of numbers
+    ///
+    /// # Usage
+    ///
+    /// $product(1,2,3)
+    pub(crate) fn product(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        let numbers = Self::parse_number_array("product", args)?;
+        Ok(Some(numbers.iter().product::<f64>().to_string()))
+    }
+
+    /// Parse a non empty array of numbers
+    fn parse_number_array(macro_name: &str, args: &str) -> RadResult<Vec<f64>> {
+        if trim!(args).is_empty() {
+            return Err(RadError::InvalidArgument(format!(
+                "{} requires an array to process but given empty value",
+                macro_name
+            )));
+        }
+        ArgParser::new()
+            .args_to_vec(args, ',', SplitVariant::Never)
+            .iter()
+            .map(|value| {
+                trim!(value).parse::<f64>().map_err(|_| {
+                    RadError::InvalidArgument(format!(
+                        "{} requires numbers but given \"{}\"",
+                        macro_name, value
+                    ))
+                })
+            })
+            .collect()
+    }
+
     /// Get max value from array
     ///
     /// # Usage
@@ -3049,6 +5199,28 @@ impl FunctionMacroMap {
         }
     }
 
+    /// Get max number from array
+    ///
+    /// # Usage
+    ///
+    /// $maxn(1,2,3,4,5)
+    pub(crate) fn get_max_number(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        let numbers = Self::parse_number_array("maxn", args)?;
+        let max = numbers.into_iter().fold(f64::NEG_INFINITY, f64::max);
+        Ok(Some(max.to_string()))
+    }
+
+    /// Get min number from array
+    ///
+    /// # Usage
+    ///
+    /// $minn(1,2,3,4,5)
+    pub(crate) fn get_min_number(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        let numbers = Self::parse_number_array("minn", args)?;
+        let min = numbers.into_iter().fold(f64::INFINITY, f64::min);
+        Ok(Some(min.to_string()))
+    }
+
     /// Get min value from array
     ///
     /// # Usage
@@ -3113,6 +5285,139 @@ impl FunctionMacroMap {
         }
     }
 
+    /// Get running totals of numbers
+    ///
+    /// # Usage
+    ///
+    /// $cumsum(1,2,3)
+    pub(crate) fn cumulative_sum(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        let values = ArgParser::new().args_to_vec(args, ',', SplitVariant::Never);
+        let mut sum = 0f64;
+        let mut sums = Vec::with_capacity(values.len());
+        for value in &values {
+            sum += trim!(value).parse::<f64>().map_err(|_| {
+                RadError::InvalidArgument(format!(
+                    "Could not convert given value \"{}\" into a floating point number",
+                    value
+                ))
+            })?;
+            sums.push(sum.to_string());
+        }
+        Ok(Some(sums.join(",")))
+    }
+
+    /// Get absolute value of a number
+    ///
+    /// This is different from "abs" macro which returns an absolute path
+    ///
+    /// # Usage
+    ///
+    /// $absn(-1.5)
+    pub(crate) fn absolute_number(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 1) {
+            let number = trim!(&args[0]).parse::<f64>().map_err(|_| {
+                RadError::InvalidArgument(format!(
+                    "Could not convert given value \"{}\" into a floating point number",
+                    args[0]
+                ))
+            })?;
+            Ok(Some(number.abs().to_string()))
+        } else {
+            Err(RadError::InvalidArgument(
+                "absn requires an argument".to_owned(),
+            ))
+        }
+    }
+
+    /// Get remainder of an integer division
+    ///
+    /// # Usage
+    ///
+    /// $mod(7,3)
+    pub(crate) fn modulo(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 2) {
+            let (lvalue, rvalue) = Self::parse_division_operands("mod", &args)?;
+            let result = lvalue
+                .checked_rem(rvalue)
+                .ok_or_else(|| RadError::InvalidArgument("mod overflowed".to_owned()))?;
+            Ok(Some(result.to_string()))
+        } else {
+            Err(RadError::InvalidArgument(
+                "mod requires two arguments".to_owned(),
+            ))
+        }
+    }
+
+    /// Get quotient of an integer division
+    ///
+    /// # Usage
+    ///
+    /// $idiv(7,3)
+    pub(crate) fn integer_division(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 2) {
+            let (lvalue, rvalue) = Self::parse_division_operands("idiv", &args)?;
+            let result = lvalue
+                .checked_div(rvalue)
+                .ok_or_else(|| RadError::InvalidArgument("idiv overflowed".to_owned()))?;
+            Ok(Some(result.to_string()))
+        } else {
+            Err(RadError::InvalidArgument(
+                "idiv requires two arguments".to_owned(),
+            ))
+        }
+    }
+
+    /// Parse integer operands of a division while checking zero division
+    fn parse_division_operands(macro_name: &str, args: &[String]) -> RadResult<(i64, i64)> {
+        let mut operands = [0i64; 2];
+        for (operand, arg) in operands.iter_mut().zip(args.iter()) {
+            *operand = trim!(arg).parse::<i64>().map_err(|_| {
+                RadError::InvalidArgument(format!(
+                    "{} requires integer operands but given \"{}\"",
+                    macro_name, arg
+                ))
+            })?;
+        }
+        if operands[1] == 0 {
+            return Err(RadError::InvalidArgument(format!(
+                "{} cannot divide by zero",
+                macro_name
+            )));
+        }
+        Ok((operands[0], operands[1]))
+    }
+
+    /// Clamp a number into a range
+    ///
+    /// # Usage
+    ///
+    /// $clamp(0,10,15)
+    pub(crate) fn clamp(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 3) {
+            let mut numbers = [0f64; 3];
+            for (number, arg) in numbers.iter_mut().zip(args.iter()) {
+                *number = trim!(arg).parse::<f64>().map_err(|_| {
+                    RadError::InvalidArgument(format!(
+                        "Could not convert given value \"{}\" into a floating point number",
+                        arg
+                    ))
+                })?;
+            }
+            let [min, max, value] = numbers;
+            if min.is_nan() || max.is_nan() || min > max {
+                return Err(RadError::InvalidArgument(format!(
+                    "clamp requires min to be smaller or equal to max but given \"{}\" and \"{}\"",
+                    min, max
+                )));
+            }
+            Ok(Some(value.clamp(min, max).to_string()))
+        } else {
+            Err(RadError::InvalidArgument(
+                "clamp requires three arguments".to_owned(),
+            ))
+        }
+    }
+
     /// Precision
     ///
     /// # Usage
@@ -3164,6 +5469,50 @@ impl FunctionMacroMap {
         }
     }
 
+    /// List names of runtime macros
+    ///
+    /// # Usage
+    ///
+    /// $macros()
+    /// $macros(true)
+    pub(crate) fn list_runtime_macros(
+        args: &str,
+        processor: &mut Processor,
+    ) -> RadResult<Option<String>> {
+        let as_lines = if trim!(args).is_empty() {
+            false
+        } else {
+            Utils::is_arg_true(args).map_err(|_| {
+                RadError::InvalidArgument(format!(
+                    "macros requires a boolean value but given \"{}\"",
+                    args
+                ))
+            })?
+        };
+        let delimiter = if as_lines {
+            processor.state.newline.clone()
+        } else {
+            ",".to_string()
+        };
+        let names = processor.get_runtime_macro_names().join(&delimiter);
+        Ok(Some(names))
+    }
+
+    /// Reverse characters of a text
+    ///
+    /// # Usage
+    ///
+    /// $revc(abcde)
+    pub(crate) fn reverse_chars(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 1) {
+            Ok(Some(args[0].chars().rev().collect()))
+        } else {
+            Err(RadError::InvalidArgument(
+                "revc requires an argument".to_owned(),
+            ))
+        }
+    }
+
     /// Declare an empty macros
     ///
     /// # Usage
@@ -3449,7 +5798,36 @@ impl FunctionMacroMap {
             Ok(None)
         } else {
             Err(RadError::InvalidArgument(
-                "Staticr requires two arguments".to_owned(),
+                "Staticr requires two arguments".to_owned(),
+            ))
+        }
+    }
+
+    /// Define a static macro which must not exist yet
+    ///
+    /// # Usage
+    ///
+    /// $staticnew(name,value)
+    pub(crate) fn define_static_new(
+        args: &str,
+        processor: &mut Processor,
+    ) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 2) {
+            let name = trim!(&args[0]);
+            let value = trim!(&args[1]);
+            // Macro name already exists
+            // Always return error regardless of error behaviour
+            if processor.contains_macro(&name, MacroType::Any) {
+                return Err(RadError::UnsoundExecution(format!(
+                    "Creating a static macro with a name already existing : \"{}\"",
+                    name
+                )));
+            }
+            processor.add_static_rules(&[(&name, &value)])?;
+            Ok(None)
+        } else {
+            Err(RadError::InvalidArgument(
+                "Staticnew requires two arguments".to_owned(),
             ))
         }
     }
@@ -3768,7 +6146,7 @@ impl FunctionMacroMap {
                 Err(_) => {
                     return Err(RadError::InvalidArgument(format!(
                         "listdir's second argument should be a boolean value but given : \"{}\"",
-                        args[0]
+                        val
                     )));
                 }
             }
@@ -3778,7 +6156,7 @@ impl FunctionMacroMap {
 
         let path;
         if let Some(val) = args.get(0) {
-            path = if val.is_empty() {
+            path = if trim!(val).is_empty() {
                 processor.get_current_dir()?
             } else {
                 PathBuf::from(trim!(val).as_ref())
@@ -3809,6 +6187,9 @@ impl FunctionMacroMap {
             }
         }
 
+        // Sort entries for deterministic output
+        vec.sort();
+
         let result: Vec<_> = vec
             .iter()
             .map(|p| p.display().to_string())
@@ -3816,6 +6197,29 @@ impl FunctionMacroMap {
         Ok(Some(result.join(delim)))
     }
 
+    /// Get paths matching a glob pattern
+    ///
+    /// # Usage
+    ///
+    /// $glob(src/*.rs)
+    #[cfg(not(feature = "wasm"))]
+    pub(crate) fn glob(args: &str, processor: &mut Processor) -> RadResult<Option<String>> {
+        if !Utils::is_granted("glob", AuthType::FIN, processor)? {
+            return Ok(None);
+        }
+        if let Some(args) = ArgParser::new().args_with_len(args, 1) {
+            let paths = Utils::glob_paths(&trim!(&args[0]))?
+                .iter()
+                .map(|p| p.display().to_string())
+                .collect::<Vec<_>>();
+            Ok(Some(paths.join(",")))
+        } else {
+            Err(RadError::InvalidArgument(
+                "glob requires an argument".to_owned(),
+            ))
+        }
+    }
+
     /// Paste unicode character in place
     /// $unicode
     pub(crate) fn paste_unicode(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
@@ -3839,6 +6243,38 @@ impl FunctionMacroMap {
         }
     }
 
+    /// Split text into groups of given characters count
+    ///
+    /// $chunk(4,deadbeef00)
+    pub(crate) fn chunk(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 2) {
+            let size = trim!(&args[0]).parse::<usize>().map_err(|_| {
+                RadError::InvalidArgument(format!(
+                    "Chunk requires positive integer number as size but got \"{}\"",
+                    &args[0]
+                ))
+            })?;
+            if size == 0 {
+                return Err(RadError::InvalidArgument(
+                    "Chunk size should be bigger than 0".to_string(),
+                ));
+            }
+            let text = &args[1];
+            let mut chunks = String::with_capacity(text.len() + text.len() / size);
+            for (index, ch) in text.chars().enumerate() {
+                if index > 0 && index % size == 0 {
+                    chunks.push(',');
+                }
+                chunks.push(ch);
+            }
+            Ok(Some(chunks))
+        } else {
+            Err(RadError::InvalidArgument(
+                "Chunk requires two arguments".to_owned(),
+            ))
+        }
+    }
+
     /// Get characters array
     ///
     /// $chars(abcde)
@@ -3919,6 +6355,481 @@ impl FunctionMacroMap {
         }
     }
 
+    /// Wrap text with a hanging indent
+    ///
+    /// * Usage
+    ///
+    /// $wrapi(80,  , Content goes here)
+    #[cfg(feature = "textwrap")]
+    pub(crate) fn wrap_indent(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        if let Some(args) = ArgParser::new().args_with_len(args, 3) {
+            let width = trim!(&args[0]).parse::<usize>()?;
+            let indent = &args[1];
+            let content = &args[2];
+            let options = textwrap::Options::new(width).subsequent_indent(indent);
+            let result = textwrap::fill(content, options);
+            Ok(Some(result))
+        } else {
+            Err(RadError::InvalidArgument(
+                "Wrapi requires three arguments".to_owned(),
+            ))
+        }
+    }
+
+    /// Wrap text while hyphenating words longer than width
+    ///
+    /// * Usage
+    ///
+    /// $wraph(80, Content goes here)
+    #[cfg(feature = "textwrap")]
+    pub(crate) fn wrap_hyphenate(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        use textwrap::core::Word;
+        if let Some(args) = ArgParser::new().args_with_len(args, 2) {
+            let width = trim!(&args[0]).parse::<usize>()?;
+            let content = &args[1];
+            let mut lines = vec![];
+            for line in content.lines() {
+                let mut words: Vec<Word> = vec![];
+                for word in textwrap::WordSeparator::new().find_words(line) {
+                    if word.width <= width {
+                        words.push(word);
+                        continue;
+                    }
+                    // Leave a room for a hyphen
+                    let mut pieces = word.break_apart(width.max(2) - 1).collect::<Vec<_>>();
+                    let last = pieces.len() - 1;
+                    pieces[..last]
+                        .iter_mut()
+                        .for_each(|piece| piece.penalty = "-");
+                    words.extend(pieces);
+                }
+                if words.is_empty() {
+                    lines.push(String::new());
+                    continue;
+                }
+                for wrapped in textwrap::wrap_algorithms::wrap_first_fit(&words, &[width as f64]) {
+                    let mut wrapped_line = String::new();
+                    for (idx, word) in wrapped.iter().enumerate() {
+                        wrapped_line.push_str(word.word);
+                        if idx == wrapped.len() - 1 {
+                            wrapped_line.push_str(word.penalty);
+                        } else {
+                            wrapped_line.push_str(word.whitespace);
+                        }
+                    }
+                    lines.push(wrapped_line);
+                }
+            }
+            Ok(Some(lines.join("\n")))
+        } else {
+            Err(RadError::InvalidArgument(
+                "Wraph requires two arguments".to_owned(),
+            ))
+        }
+    }
+
+    /// Wrap text by a percentage of a base width
+    ///
+    /// Base width is read from "RAD_WRAP_BASE_WIDTH" and defaults to 80
+    ///
+    /// * Usage
+    ///
+    /// $wrapp(50, Content goes here)
+    #[cfg(feature = "textwrap")]
+    pub(crate) fn wrap_proportional(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        use crate::consts::{WRAP_BASE_WIDTH, WRAP_BASE_WIDTH_ENV};
+        if let Some(args) = ArgParser::new().args_with_len(args, 2) {
+            let percent = trim!(&args[0]).parse::<usize>().map_err(|_| {
+                RadError::InvalidArgument(format!(
+                    "Wrapp requires a percentage between 1 and 100 but given \"{}\"",
+                    args[0]
+                ))
+            })?;
+            if percent == 0 || percent > 100 {
+                return Err(RadError::InvalidArgument(format!(
+                    "Wrapp requires a percentage between 1 and 100 but given \"{}\"",
+                    percent
+                )));
+            }
+            let base_width = match std::env::var(WRAP_BASE_WIDTH_ENV) {
+                Ok(width) => width.trim().parse::<usize>().map_err(|_| {
+                    RadError::InvalidArgument(format!(
+                        "{} should be an unsigned integer but given \"{}\"",
+                        WRAP_BASE_WIDTH_ENV, width
+                    ))
+                })?,
+                Err(_) => WRAP_BASE_WIDTH,
+            };
+            let width = (base_width * percent / 100).max(1);
+            let content = &args[1];
+            let result = textwrap::fill(content, width);
+            Ok(Some(result))
+        } else {
+            Err(RadError::InvalidArgument(
+                "Wrapp requires two arguments".to_owned(),
+            ))
+        }
+    }
+
+    /// Get a scalar value from json by a path
+    ///
+    /// * Usage
+    ///
+    /// $jsonget(a.b[0].c,{"a":{"b":[{"c":1}]}})
+    #[cfg(feature = "json")]
+    pub(crate) fn json_get(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        use serde_json::Value;
+        if let Some(args) = ArgParser::new().args_with_len(args, 2) {
+            let path = trim!(&args[0]);
+            let mut value: &Value = &serde_json::from_str(&args[1]).map_err(|err| {
+                RadError::InvalidArgument(format!("Jsonget failed to parse json : {}", err))
+            })?;
+            let missing = |segment: &str| {
+                RadError::InvalidArgument(format!(
+                    "Jsonget could not find \"{}\" in path \"{}\"",
+                    segment, path
+                ))
+            };
+            for segment in path.split('.').filter(|s| !s.is_empty()) {
+                let (key, mut indices) = match segment.find('[') {
+                    Some(index) => segment.split_at(index),
+                    None => (segment, ""),
+                };
+                if !key.is_empty() {
+                    value = value
+                        .as_object()
+                        .and_then(|obj| obj.get(key))
+                        .ok_or_else(|| missing(key))?;
+                }
+                while !indices.is_empty() {
+                    let (index, rest) = indices
+                        .strip_prefix('[')
+                        .and_then(|s| s.split_once(']'))
+                        .ok_or_else(|| {
+                        RadError::InvalidArgument(format!(
+                            "Jsonget got an invalid path \"{}\"",
+                            path
+                        ))
+                    })?;
+                    let position = index.trim().parse::<usize>().map_err(|_| {
+                        RadError::InvalidArgument(format!(
+                            "Jsonget requires an unsigned integer as index but given \"{}\"",
+                            index
+                        ))
+                    })?;
+                    value = value
+                        .as_array()
+                        .and_then(|arr| arr.get(position))
+                        .ok_or_else(|| missing(&format!("[{}]", index)))?;
+                    indices = rest;
+                }
+            }
+            match value {
+                Value::String(text) => Ok(Some(text.to_owned())),
+                Value::Number(_) | Value::Bool(_) | Value::Null => Ok(Some(value.to_string())),
+                _ => Err(RadError::InvalidArgument(format!(
+                    "Jsonget requires a path to a scalar value but \"{}\" is not",
+                    path
+                ))),
+            }
+        } else {
+            Err(RadError::InvalidArgument(
+                "Jsonget requires two arguments".to_owned(),
+            ))
+        }
+    }
+
+    /// Get a scalar value from toml by a dotted path
+    ///
+    /// * Usage
+    ///
+    /// $tomlget(package.version,$include(Cargo.toml))
+    #[cfg(feature = "toml")]
+    pub(crate) fn toml_get(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        use toml::Value;
+        if let Some(args) = ArgParser::new().args_with_len(args, 2) {
+            let path = trim!(&args[0]);
+            let table = args[1].parse::<toml::Table>().map_err(|err| {
+                RadError::InvalidArgument(format!("Tomlget failed to parse toml : {}", err))
+            })?;
+            let mut keys = path.split('.');
+            let missing = || {
+                RadError::InvalidArgument(format!(
+                    "Tomlget could not find a value with path \"{}\"",
+                    path
+                ))
+            };
+            let mut value = keys
+                .next()
+                .and_then(|key| table.get(key.trim()))
+                .ok_or_else(missing)?;
+            for key in keys {
+                value = value.get(key.trim()).ok_or_else(missing)?;
+            }
+            match value {
+                Value::String(text) => Ok(Some(text.to_owned())),
+                Value::Integer(number) => Ok(Some(number.to_string())),
+                Value::Float(number) => Ok(Some(number.to_string())),
+                Value::Boolean(boolean) => Ok(Some(boolean.to_string())),
+                Value::Datetime(datetime) => Ok(Some(datetime.to_string())),
+                Value::Array(_) | Value::Table(_) => Err(RadError::InvalidArgument(format!(
+                    "Tomlget requires a path to a scalar value but \"{}\" is not",
+                    path
+                ))),
+            }
+        } else {
+            Err(RadError::InvalidArgument(
+                "Tomlget requires two arguments".to_owned(),
+            ))
+        }
+    }
+
+    /// Get a scalar value from yaml frontmatter
+    ///
+    /// * Usage
+    ///
+    /// $frontmatter(title,$include(post.md))
+    #[cfg(feature = "yaml")]
+    pub(crate) fn get_frontmatter(args: &str, p: &mut Processor) -> RadResult<Option<String>> {
+        use yaml_rust2::{Yaml, YamlLoader};
+        if let Some(args) = ArgParser::new().args_with_len(args, 2) {
+            let key = trim!(&args[0]);
+            let mut lines = args[1].lines();
+            let mut block = String::new();
+            let mut closed = false;
+            if lines.next().map(str::trim_end) == Some("---") {
+                for line in lines {
+                    if line.trim_end() == "---" {
+                        closed = true;
+                        break;
+                    }
+                    block.push_str(line);
+                    block.push('\n');
+                }
+            }
+            if !closed {
+                if p.state.behaviour == ErrorBehaviour::Strict {
+                    return Err(RadError::InvalidArgument(
+                        "Frontmatter could not find a frontmatter block from a document".to_owned(),
+                    ));
+                }
+                return Ok(None);
+            }
+            let documents = YamlLoader::load_from_str(&block).map_err(|err| {
+                RadError::InvalidArgument(format!("Frontmatter failed to parse yaml : {}", err))
+            })?;
+            let value = documents
+                .first()
+                .map(|frontmatter| &frontmatter[key.as_ref()])
+                .unwrap_or(&Yaml::BadValue);
+            match value {
+                // Real is kept as original representation
+                Yaml::String(text) | Yaml::Real(text) => Ok(Some(text.to_owned())),
+                Yaml::Integer(number) => Ok(Some(number.to_string())),
+                Yaml::Boolean(boolean) => Ok(Some(boolean.to_string())),
+                Yaml::Null => Ok(None),
+                Yaml::BadValue => Err(RadError::InvalidArgument(format!(
+                    "Frontmatter could not find a key \"{}\"",
+                    key
+                ))),
+                _ => Err(RadError::InvalidArgument(format!(
+                    "Frontmatter requires a key to a scalar value but \"{}\" is not",
+                    key
+                ))),
+            }
+        } else {
+            Err(RadError::InvalidArgument(
+                "Frontmatter requires two arguments".to_owned(),
+            ))
+        }
+    }
+
+    /// Generate a random version 4 uuid
+    ///
+    /// * Usage
+    ///
+    /// $uuid()
+    #[cfg(feature = "uuid")]
+    pub(crate) fn uuid_v4(_: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        Ok(Some(uuid::Uuid::new_v4().to_string()))
+    }
+
+    /// Generate a version 5 uuid from a namespace and a name
+    ///
+    /// * Usage
+    ///
+    /// $uuid5(dns,example.com)
+    #[cfg(feature = "uuid")]
+    pub(crate) fn uuid_v5(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        use uuid::Uuid;
+        if let Some(args) = ArgParser::new().args_with_len(args, 2) {
+            let namespace = trim!(&args[0]);
+            let namespace = match namespace.to_lowercase().as_str() {
+                "dns" => Uuid::NAMESPACE_DNS,
+                "url" => Uuid::NAMESPACE_URL,
+                "oid" => Uuid::NAMESPACE_OID,
+                "x500" => Uuid::NAMESPACE_X500,
+                _ => Uuid::parse_str(&namespace).map_err(|_| {
+                    RadError::InvalidArgument(format!(
+                        "Uuid5 requires dns, url, oid, x500 or a uuid as namespace but given \"{}\"",
+                        namespace
+                    ))
+                })?,
+            };
+            Ok(Some(
+                Uuid::new_v5(&namespace, args[1].as_bytes()).to_string(),
+            ))
+        } else {
+            Err(RadError::InvalidArgument(
+                "Uuid5 requires two arguments".to_owned(),
+            ))
+        }
+    }
+
+    /// Choose a value at random
+    ///
+    /// * Usage
+    ///
+    /// $choose(a,b,c)
+    #[cfg(feature = "rand")]
+    pub(crate) fn choose(args: &str, p: &mut Processor) -> RadResult<Option<String>> {
+        use rand::seq::SliceRandom;
+        let values = ArgParser::new().args_to_vec(args, ',', SplitVariant::Never);
+        let chosen = values.choose(&mut p.state.rng).ok_or_else(|| {
+            RadError::InvalidArgument("Choose requires at least one value".to_owned())
+        })?;
+        Ok(Some(chosen.to_owned()))
+    }
+
+    /// Shuffle an array
+    ///
+    /// * Usage
+    ///
+    /// $shuffle(a,b,c)
+    #[cfg(feature = "rand")]
+    pub(crate) fn shuffle(args: &str, p: &mut Processor) -> RadResult<Option<String>> {
+        use rand::seq::SliceRandom;
+        let mut values = ArgParser::new().args_to_vec(args, ',', SplitVariant::Never);
+        values.shuffle(&mut p.state.rng);
+        Ok(Some(values.join(",")))
+    }
+
+    /// Get a random integer in range
+    ///
+    /// * Usage
+    ///
+    /// $randint(1,6)
+    #[cfg(feature = "rand")]
+    pub(crate) fn random_int(args: &str, p: &mut Processor) -> RadResult<Option<String>> {
+        use rand::Rng;
+        if let Some(args) = ArgParser::new().args_with_len(args, 2) {
+            let parse = |value: &str| {
+                trim!(value).parse::<isize>().map_err(|_| {
+                    RadError::InvalidArgument(format!(
+                        "Randint requires an integer but given \"{}\"",
+                        value
+                    ))
+                })
+            };
+            let (min, max) = (parse(&args[0])?, parse(&args[1])?);
+            if min > max {
+                return Err(RadError::InvalidArgument(format!(
+                    "Randint requires min to be less or equal to max but given \"{}\" and \"{}\"",
+                    min, max
+                )));
+            }
+            Ok(Some(p.state.rng.gen_range(min..=max).to_string()))
+        } else {
+            Err(RadError::InvalidArgument(
+                "Randint requires two arguments".to_owned(),
+            ))
+        }
+    }
+
+    /// Compare two texts line by line
+    ///
+    /// * Usage
+    ///
+    /// $diff(old text,new text)
+    #[cfg(feature = "debug")]
+    pub(crate) fn diff(args: &str, p: &mut Processor) -> RadResult<Option<String>> {
+        use similar::ChangeTag;
+        if let Some(args) = ArgParser::new().args_with_len(args, 2) {
+            // Terminate last lines so that missing trailing newline is not a change
+            let (old, new) = (format!("{}\n", args[0]), format!("{}\n", args[1]));
+            let result = similar::TextDiff::from_lines(&old, &new);
+            let mut diff = vec![];
+            for change in result.iter_all_changes() {
+                let sign = match change.tag() {
+                    ChangeTag::Delete => "-",
+                    ChangeTag::Insert => "+",
+                    ChangeTag::Equal => " ",
+                };
+                diff.push(format!(
+                    "{} {}",
+                    sign,
+                    change.value().trim_end_matches(['\r', '\n'])
+                ));
+            }
+            Ok(Some(diff.join(&p.state.newline)))
+        } else {
+            Err(RadError::InvalidArgument(
+                "Diff requires two arguments".to_owned(),
+            ))
+        }
+    }
+
+    /// Encode content into base64
+    ///
+    /// * Usage
+    ///
+    /// $b64enc(Content goes here)
+    #[cfg(feature = "base64")]
+    pub(crate) fn base64_encode(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        use base64::Engine;
+        if let Some(args) = ArgParser::new().args_with_len(args, 1) {
+            Ok(Some(
+                base64::engine::general_purpose::STANDARD.encode(args[0].as_bytes()),
+            ))
+        } else {
+            Err(RadError::InvalidArgument(
+                "B64enc requires an argument".to_owned(),
+            ))
+        }
+    }
+
+    /// Decode base64 content
+    ///
+    /// * Usage
+    ///
+    /// $b64dec(Q29udGVudA==)
+    #[cfg(feature = "base64")]
+    pub(crate) fn base64_decode(args: &str, _: &mut Processor) -> RadResult<Option<String>> {
+        use base64::Engine;
+        if let Some(args) = ArgParser::new().args_with_len(args, 1) {
+            let source = trim!(&args[0]);
+            let bytes = base64::engine::general_purpose::STANDARD
+                .decode(source.as_bytes())
+                .map_err(|err| {
+                    RadError::InvalidArgument(format!(
+                        "Failed to decode base64 string \"{}\" : {}",
+                        source, err
+                    ))
+                })?;
+            let decoded = String::from_utf8(bytes).map_err(|_| {
+                RadError::InvalidArgument(format!(
+                    "Decoded content of \"{}\" is not a valid utf8 text",
+                    source
+                ))
+            })?;
+            Ok(Some(decoded))
+        } else {
+            Err(RadError::InvalidArgument(
+                "B64dec requires an argument".to_owned(),
+            ))
+        }
+    }
+
     /// Update storage
     ///
     /// # Usage
@@ -4119,3 +7030,62 @@ struct ListCounterByLevel {
     // key means level and value means total count of list items
     counts: HashMap<usize, usize>,
 }
+
+/// Ordering for sort macros
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum OrderType {
+    /// Lexicographic ascending order
+    Ascending,
+    /// Lexicographic descending order
+    Descending,
+    /// Numeric ascending order
+    Numeric,
+}
+
+impl OrderType {
+    /// Sort given items in place
+    ///
+    /// Numeric order fails if any item cannot be parsed as a number
+    fn sort(&self, items: &mut Vec<&str>) -> RadResult<()> {
+        match self {
+            Self::Ascending => items.sort_unstable(),
+            Self::Descending => {
+                items.sort_unstable();
+                items.reverse()
+            }
+            Self::Numeric => {
+                let mut numbers = Vec::with_capacity(items.len());
+                for item in items.iter() {
+                    let number = item.trim().parse::<f64>().map_err(|_| {
+                        RadError::InvalidArgument(format!(
+                            "Numeric sort requires every item to be a number but given \"{}\"",
+                            item
+                        ))
+                    })?;
+                    numbers.push((number, *item));
+                }
+                numbers.sort_by(|a, b| a.0.total_cmp(&b.0));
+                *items = numbers.into_iter().map(|(_, item)| item).collect();
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for OrderType {
+    type Err = RadError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let order_type = match s.to_lowercase().as_str() {
+            "asec" => Self::Ascending,
+            "desc" => Self::Descending,
+            "num" => Self::Numeric,
+            _ => {
+                return Err(RadError::InvalidArgument(format!(
+                    "Sort requires either asec, desc or num but given \"{}\"",
+                    s
+                )))
+            }
+        };
+        Ok(order_type)
+    }
+}
@@ -133,6 +133,32 @@ impl MacroMap {
         }
     }
 
+    /// Get sorted names of macros
+    pub fn get_names(&self, macro_type: MacroType, hygiene_type: Hygiene) -> Vec<String> {
+        let mut names: Vec<String> = match macro_type {
+            MacroType::Deterred => self.deterred.macros.keys().cloned().collect(),
+            MacroType::Function => self.function.macros.keys().cloned().collect(),
+            MacroType::Runtime => self
+                .runtime
+                .get_names(hygiene_type)
+                .into_iter()
+                .map(|s| s.to_owned())
+                .collect(),
+            MacroType::Any => self
+                .deterred
+                .macros
+                .keys()
+                .chain(self.function.macros.keys())
+                .map(|s| s.as_str())
+                .chain(self.runtime.get_names(hygiene_type))
+                .map(|s| s.to_owned())
+                .collect(),
+        };
+        names.sort_unstable();
+        names.dedup();
+        names
+    }
+
     /// Add new anonymous macro
     pub fn new_anon_macro(&mut self, body: &str) -> RadResult<()> {
         self.anon_map.new_macro(body)
@@ -101,6 +101,24 @@ impl RuntimeMacroMap {
         }
     }
 
+    /// Get sorted names of macros
+    ///
+    /// Volatile macros are included unless hygiene is aseptic
+    pub fn get_names(&self, hygiene_type: Hygiene) -> Vec<&str> {
+        let mut names: Vec<&str> = match hygiene_type {
+            Hygiene::Aseptic => self.macros.keys().map(|s| s.as_str()).collect(),
+            _ => self
+                .macros
+                .keys()
+                .chain(self.volatile.keys())
+                .map(|s| s.as_str())
+                .collect(),
+        };
+        names.sort_unstable();
+        names.dedup();
+        names
+    }
+
     /// Get macro by name
     pub fn get(&self, key: &str, hygiene_type: Hygiene) -> Option<&RuntimeMacro> {
         match hygiene_type {
@@ -171,6 +171,8 @@ pub struct Processor<'processor> {
     checker: UnbalancedChecker,
     pub(crate) state: ProcessorState,
     pub(crate) storage: Option<Box<dyn RadStorage>>,
+    line_callback: Option<Box<dyn FnMut(usize) + 'processor>>,
+    buffer_depth: usize,
     #[cfg(feature = "cindex")]
     pub(crate) indexer: Indexer,
 }
@@ -250,6 +252,8 @@ impl<'processor> Processor<'processor> {
             debugger: Debugger::new(),
             checker: UnbalancedChecker::new(),
             storage: None,
+            line_callback: None,
+            buffer_depth: 0,
             #[cfg(feature = "cindex")]
             indexer: Indexer::new(),
         }
@@ -489,6 +493,98 @@ impl<'processor> Processor<'processor> {
         self
     }
 
+    /// Set whether to trim blank lines left by empty expansions
+    ///
+    /// By default, a macro that expands to an empty string leaves its line's newline in place
+    /// while a macro that yields nothing consumes it. If this is set to true, an empty expansion
+    /// on its own line also consumes the trailing newline, which removes stray blank lines from
+    /// conditional macros such as "if" or "ifelse". This applies to nested conditionals as well
+    /// because an outer macro expands to empty string when every inner macro did.
+    ///
+    /// This works independently of "enl" and "dnl". "enl" still escapes a newline of a line with
+    /// content and "dnl" still denies a next empty line.
+    ///
+    /// ```rust
+    /// let proc = r4d::Processor::empty()
+    ///     .trim_conditional_blanks(true);
+    /// ```
+    pub fn trim_conditional_blanks(mut self, trim: bool) -> Self {
+        self.state.trim_conditional_blanks = trim;
+        self
+    }
+
+    /// Set maximum depth of nested macro invocations
+    ///
+    /// Processing fails when macro invocation is nested deeper than the depth. This prevents
+    /// infinitely self-referencing macros from overflowing a stack. Depth 0 disables the limit.
+    ///
+    /// ```rust
+    /// let proc = r4d::Processor::empty()
+    ///     .max_macro_depth(512);
+    /// ```
+    pub fn max_macro_depth(mut self, depth: usize) -> Self {
+        self.state.max_macro_depth = depth;
+        self
+    }
+
+    /// Set whether to fail on undefined macros regardless of error behaviour
+    ///
+    /// If this is set to true, an invocation of an undefined macro returns a "NoSuchMacroName"
+    /// error to the caller even in lenient or purge mode. Lenient mode would otherwise print the
+    /// invocation as it is and purge mode would silently remove it. Other errors still follow the
+    /// error behaviour. Dry run still warns on undefined macros because macros are not expanded.
+    ///
+    /// ```rust
+    /// let proc = r4d::Processor::empty()
+    ///     .purge(true)
+    ///     .strict_undefined(true);
+    /// ```
+    pub fn strict_undefined(mut self, strict: bool) -> Self {
+        self.state.strict_undefined = strict;
+        self
+    }
+
+    /// Set whether to count macro calls
+    ///
+    /// Counted calls can be retrieved with "macro_call_stats" method. This works in dry run as
+    /// well, which is useful to audit which macros are exercised by a template.
+    ///
+    /// ```rust
+    /// let proc = r4d::Processor::empty()
+    ///     .count_macro_calls(true);
+    /// ```
+    pub fn count_macro_calls(mut self, count: bool) -> Self {
+        self.state.count_macro_calls = count;
+        self
+    }
+
+    /// Set whether to log errors caught by "try" macro as warnings
+    ///
+    /// This is true by default. If set to false, try macro silently expands a fallback.
+    ///
+    /// ```rust
+    /// let proc = r4d::Processor::empty()
+    ///     .warn_try_errors(false);
+    /// ```
+    pub fn warn_try_errors(mut self, warn: bool) -> Self {
+        self.state.warn_try_errors = warn;
+        self
+    }
+
+    /// Set timeout for system commands
+    ///
+    /// A system command invoked by "syscmd" is killed when it doesn't finish within the timeout.
+    /// Without a timeout, a command is waited indefinitely.
+    ///
+    /// ```rust
+    /// let proc = r4d::Processor::empty()
+    ///     .shell_timeout(std::time::Duration::from_secs(10));
+    /// ```
+    pub fn shell_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.state.shell_timeout.replace(timeout);
+        self
+    }
+
     /// Set comment type
     ///
     /// By default, comment is disabled for better compatibility.
@@ -696,6 +792,20 @@ impl<'processor> Processor<'processor> {
         self
     }
 
+    /// Build with a callback which is called after each line is processed
+    ///
+    /// Callback is given a number of lines consumed from a top level input. Nested inputs such as
+    /// included files or macro arguments don't invoke the callback.
+    ///
+    /// ```rust
+    /// let proc = r4d::Processor::empty()
+    ///     .on_line_processed(Box::new(|line| eprintln!("Processed line {}", line)));
+    /// ```
+    pub fn on_line_processed(mut self, callback: Box<dyn FnMut(usize) + 'processor>) -> Self {
+        self.line_callback.replace(callback);
+        self
+    }
+
     // </BUILDER>
     // End builder methods
     // ----------
@@ -801,6 +911,19 @@ impl<'processor> Processor<'processor> {
         self.state.hygiene = hygiene;
     }
 
+    /// Get counts of macro calls by names
+    ///
+    /// Calls are only counted when "count_macro_calls" is enabled.
+    ///
+    /// ```rust
+    /// let mut proc = r4d::Processor::new().count_macro_calls(true);
+    /// proc.process_string(None, "$len(a)$len(b)").expect("Failed to process a string");
+    /// assert_eq!(Some(&2), proc.macro_call_stats().get("len"));
+    /// ```
+    pub fn macro_call_stats(&self) -> HashMap<String, usize> {
+        self.state.macro_call_stats.clone()
+    }
+
     /// Set to dry run mode
     pub fn set_dry_mode(&mut self) {
         self.write_option = WriteOption::Discard;
@@ -829,6 +952,45 @@ impl<'processor> Processor<'processor> {
         }
     }
 
+    /// Reset processing state for reuse
+    ///
+    /// Cleared states are
+    /// - Pipes
+    /// - Relay targets
+    /// - Flow control
+    /// - Volatile, local and anonymous macros
+    /// - Queued contents, input stack and pending newline controls
+    /// - Line tracking, error and warning counts and error cache
+    /// - Macro call counts
+    ///
+    /// Preserved states are
+    /// - Non-volatile runtime macros, function and deterred macros
+    /// - Configurations such as write option, permissions, hygiene, error behaviour and hooks
+    ///
+    /// ```rust
+    /// let mut proc = r4d::Processor::empty();
+    /// proc.reset();
+    /// ```
+    pub fn reset(&mut self) {
+        self.state.clear_pipes();
+        self.state.relay.clear();
+        self.state.flow_control = FlowControl::None;
+        self.map.clear_runtime_macros(true);
+        self.map.clear_local();
+        self.map.clear_anonymous_macros();
+        self.state.queued.clear();
+        self.state.input_stack.clear();
+        self.state.paused = false;
+        self.state.deny_newline = false;
+        self.state.consume_newline = false;
+        self.state.escape_newline = false;
+        self.state.stream_state.clear();
+        self.state.error_cache = None;
+        self.state.macro_call_stats.clear();
+        self.cache.clear();
+        self.logger.reset();
+    }
+
     /// Toggle macro hygiene
     pub(crate) fn toggle_hygiene(&mut self, toggle: bool) {
         if toggle {
@@ -861,6 +1023,20 @@ impl<'processor> Processor<'processor> {
         self.state.flow_control = FlowControl::None;
     }
 
+    /// Get sorted names of macros
+    ///
+    /// This doesn't require a signature feature.
+    ///
+    /// ```rust
+    /// use r4d::MacroType;
+    /// let proc = r4d::Processor::new();
+    /// let names = proc.get_macro_names(MacroType::Function);
+    /// assert!(names.contains(&"len".to_string()));
+    /// ```
+    pub fn get_macro_names(&self, macro_type: MacroType) -> Vec<String> {
+        self.map.get_names(macro_type, self.state.hygiene)
+    }
+
     /// Get macro signatrue map
     #[cfg(feature = "signature")]
     pub(crate) fn get_signature_map(&self, sig_type: SignatureType) -> RadResult<SignatureMap> {
@@ -1092,6 +1268,69 @@ impl<'processor> Processor<'processor> {
         Ok(())
     }
 
+    /// Add runtime macros(rules) from a source text
+    ///
+    /// Each line is either "name=body" or "name(args)=body". Arguments are separated by commas
+    /// or whitespaces. A line ending with a backslash continues to a next line and lines starting
+    /// with a comment character are ignored. Body is not expanded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let mut processor = r4d::Processor::empty();
+    /// processor.add_rules_from_str("% Greetings\ngreet(name)=Hello $name()\nbye=Good \\\nbye")
+    ///     .expect("Failed to add rules");
+    /// ```
+    pub fn add_rules_from_str(&mut self, source: &str) -> RadResult<()> {
+        let comment_char = self.get_comment_char();
+        let mut lines = source.lines().enumerate();
+        while let Some((idx, line)) = lines.next() {
+            let idx = idx + 1; // 1 starting index is more human friendly
+            if line.trim().is_empty() || line.trim_start().starts_with(comment_char) {
+                continue;
+            }
+
+            // Join continued lines
+            let mut line = line.to_string();
+            while line.ends_with('\\') {
+                line.pop();
+                match lines.next() {
+                    Some((_, next)) => {
+                        line.push_str(&self.state.newline);
+                        line.push_str(next);
+                    }
+                    None => break,
+                }
+            }
+
+            let invalid_line = || {
+                RadError::InvalidArgument(format!(
+                    "Invalid line in rules, line \"{}\" \n = \"{}\"",
+                    idx, line
+                ))
+            };
+            let (head, body) = line.split_once('=').ok_or_else(invalid_line)?;
+            let (name, args) = match head.split_once('(') {
+                Some((name, args)) => (
+                    name,
+                    args.trim_end()
+                        .strip_suffix(')')
+                        .ok_or_else(invalid_line)?
+                        .replace(',', " "),
+                ),
+                None => (head, String::new()),
+            };
+            self.add_runtime_rules(&[(name, args.as_str(), body)])
+                .map_err(|err| {
+                    RadError::InvalidArgument(format!(
+                        "Invalid line in rules, line \"{}\" \n = {}",
+                        idx, err
+                    ))
+                })?;
+        }
+        Ok(())
+    }
+
     /// Add static (macros) rules without builder pattern
     ///
     /// **NOTE** that this method doesn't expand body, but needs to be handled before invoking this method
@@ -1268,6 +1507,25 @@ impl<'processor> Processor<'processor> {
         self.organize_and_clear_cache()
     }
 
+    /// Expand a string and return the result instead of writing it
+    ///
+    /// Write option is restored after expansion even if an error occurred.
+    ///
+    /// ```rust
+    /// let mut proc = r4d::Processor::new();
+    /// let expanded = proc.expand_string("$len(abc)")
+    ///     .expect("Failed to expand a string");
+    /// assert_eq!("3", expanded);
+    /// ```
+    pub fn expand_string(&mut self, content: &str) -> RadResult<String> {
+        let write_option = std::mem::replace(&mut self.write_option, WriteOption::Return);
+        let cache = std::mem::take(&mut self.cache);
+        let result = self.process_string(None, content);
+        self.write_option = write_option;
+        self.cache = cache;
+        Ok(result?.unwrap_or_default())
+    }
+
     /// Read from piece
     ///
     /// ```rust
@@ -1473,6 +1731,73 @@ impl<'processor> Processor<'processor> {
         Ok(None)
     }
 
+    /// Process lines for streaming through a pipeline of macros
+    ///
+    /// Each line is given to the first macro as a pipe input and an output of a macro becomes a
+    /// pipe input of the next macro. Only the output of the last macro is written.
+    ///
+    /// ```no_run
+    /// let mut proc = r4d::Processor::empty();
+    /// proc.stream_pipeline(..., None, vec!["trim", "upper"])
+    ///     .expect("Failed to process stream pipeline");
+    /// ```
+    pub fn stream_pipeline(
+        &mut self,
+        buffer: impl std::io::BufRead,
+        current_target: Option<&str>,
+        macros: Vec<&str>,
+    ) -> RadResult<Option<String>> {
+        if macros.is_empty() {
+            return Err(RadError::InvalidMacroReference(
+                "Cannot invoke an empty macro pipeline".to_string(),
+            ));
+        }
+        self.state.stream_state.on_stream = true;
+        // Sandboxed environment, backup
+        let backup = if self.state.sandbox {
+            Some(self.backup())
+        } else {
+            None
+        };
+
+        // Set target
+        if let Some(file) = current_target {
+            self.set_file(file)?;
+        } else {
+            self.set_input_stdin()?;
+        }
+
+        let mut frags = macros
+            .iter()
+            .map(|name| {
+                let mut frag = MacroFragment::new();
+                frag.pipe_input = true;
+                frag.name = name.to_string();
+                frag
+            })
+            .collect::<Vec<_>>();
+        for line in buffer.lines() {
+            let mut value = line?;
+            for frag in frags.iter_mut() {
+                self.state.add_pipe(None, value);
+                value = self
+                    .execute_macro_with_frag(0, MAIN_CALLER, frag)?
+                    .unwrap_or_default();
+            }
+            self.write_to(&value, &ContainerType::None, &mut None)?;
+            self.logger.inc_line_number();
+        }
+
+        // Recover previous state from sandboxed processing
+        if let Some(backup) = backup {
+            self.recover(backup)?;
+            self.state.sandbox = false;
+        }
+
+        self.state.stream_state.on_stream = false;
+        Ok(None)
+    }
+
     /// Process contents from a static script
     ///
     /// ```no_run
@@ -1538,11 +1863,27 @@ impl<'processor> Processor<'processor> {
     }
 
     /// Internal method for processing buffers line by line
+    ///
+    /// This tracks nesting depth of buffers so that line callback is only called for a top level
+    /// input.
     fn process_buffer(
         &mut self,
         buffer: &mut impl std::io::BufRead,
         backup: Option<SandboxBackup>,
         cont_type: ContainerType,
+    ) -> RadResult<Option<String>> {
+        self.buffer_depth += 1;
+        let result = self.process_buffer_lines(buffer, backup, cont_type);
+        self.buffer_depth -= 1;
+        result
+    }
+
+    /// Process buffer lines
+    fn process_buffer_lines(
+        &mut self,
+        buffer: &mut impl std::io::BufRead,
+        backup: Option<SandboxBackup>,
+        cont_type: ContainerType,
     ) -> RadResult<Option<String>> {
         let mut line_iter = Utils::full_lines(buffer).peekable();
         let mut lexor = Lexor::new(
@@ -1565,6 +1906,7 @@ impl<'processor> Processor<'processor> {
             self.logger.get_last_line(),
         )?;
 
+        let mut line_number = 0;
         loop {
             #[cfg(feature = "debug")]
             if let Some(line) = line_iter.peek() {
@@ -1618,6 +1960,13 @@ impl<'processor> Processor<'processor> {
             // Increase absolute line number
             #[cfg(feature = "debug")]
             self.debugger.inc_line_number();
+
+            line_number += 1;
+            if self.buffer_depth == 1 {
+                if let Some(callback) = self.line_callback.as_mut() {
+                    callback(line_number);
+                }
+            }
         } // Loop end
 
         // Recover previous state from sandboxed processing
@@ -2027,6 +2376,13 @@ impl<'processor> Processor<'processor> {
         // Increase level to represent nestedness
         let level = level + 1;
 
+        if self.state.max_macro_depth != 0 && level > self.state.max_macro_depth {
+            return Err(RadError::InvalidExecution(format!(
+                "Macro \"{}\" exceeded maximum macro depth of {}",
+                frag.name, self.state.max_macro_depth
+            )));
+        }
+
         // Assign local variables
         let (name, mut raw_args) = (&frag.name, frag.args.clone());
 
@@ -2118,6 +2474,20 @@ impl<'processor> Processor<'processor> {
             }
             temp_level -= 1;
         }
+
+        // Count a resolved macro call
+        if self.state.count_macro_calls
+            && self
+                .map
+                .contains_macro(name, MacroType::Any, self.state.hygiene)
+        {
+            *self
+                .state
+                .macro_call_stats
+                .entry(name.to_owned())
+                .or_insert(0) += 1;
+        }
+
         // SPECIAL MACROS
         if name == MACRO_SPECIAL_ANON && self.map.get_anon_macro().is_some() {
             let result = self.invoke_runtime(level, None, &args)?;
@@ -2326,9 +2696,12 @@ impl<'processor> Processor<'processor> {
             // Dry run if such mode is set
             if self.state.process_type == ProcessType::Dry {
                 let err = RadError::InvalidArgument(format!("Macro \"{}\" has invalid body", name));
+                // Validation is not a real call, thus don't count it
+                let count_macro_calls = std::mem::take(&mut self.state.count_macro_calls);
                 let res = self
                     .process_string(None, &format!("${}({})", name, args.replace(' ', ",")))
                     .map_err(|_| &err);
+                self.state.count_macro_calls = count_macro_calls;
 
                 if res.is_err() {
                     self.log_warning(&err.to_string(), WarningType::Sanity)?;
@@ -2639,7 +3012,11 @@ impl<'processor> Processor<'processor> {
             self.log_error(&err.to_string())?;
             match self.state.behaviour {
                 ErrorBehaviour::Interrupt => return Err(err),
-                ErrorBehaviour::Assert => return Err(RadError::AssertFail),
+                ErrorBehaviour::Assert => {
+                    // Cache the original error so that a caller can inspect it
+                    self.state.error_cache.get_or_insert(err);
+                    return Err(RadError::AssertFail);
+                }
                 // Re-throw error
                 // It is not captured in cli but it can be handled by library user.
                 ErrorBehaviour::Strict => {
@@ -2686,7 +3063,10 @@ impl<'processor> Processor<'processor> {
 
                 // Handle empty name error
                 match self.state.behaviour {
-                    ErrorBehaviour::Assert => return Err(RadError::AssertFail),
+                    ErrorBehaviour::Assert => {
+                        self.state.error_cache.get_or_insert(err);
+                        return Err(RadError::AssertFail);
+                    }
                     ErrorBehaviour::Strict | ErrorBehaviour::Interrupt => {
                         return Err(RadError::StrictPanic);
                     } // Error
@@ -2777,6 +3157,13 @@ impl<'processor> Processor<'processor> {
             return Err(error);
         }
 
+        // Undefined macro is not permitted regardless of behaviour if configured so
+        if self.state.strict_undefined {
+            if let RadError::NoSuchMacroName(..) = error {
+                return Err(error);
+            }
+        }
+
         if self.state.error_cache.is_none() {
             self.log_error(&error.to_string())?;
             self.state.error_cache.replace(error);
@@ -2830,6 +3217,11 @@ impl<'processor> Processor<'processor> {
                 }
             }
 
+            // Empty expansion acts as if nothing was returned
+            if self.state.trim_conditional_blanks && content.is_empty() {
+                self.state.consume_newline = true;
+            }
+
             // Negate result
             if frag.negate_result {
                 match Utils::is_arg_true(trim!(&content).as_ref()) {
@@ -3042,6 +3434,10 @@ impl<'processor> Processor<'processor> {
 
     /// Log error message
     pub(crate) fn log_error(&mut self, log: &str) -> RadResult<()> {
+        // Errors are expected inside a failure assertion, thus don't log them
+        if self.state.behaviour == ErrorBehaviour::Assert {
+            return Ok(());
+        }
         self.logger.elog(log)?;
         Ok(())
     }
@@ -3196,6 +3592,16 @@ impl<'processor> Processor<'processor> {
         Ok(body)
     }
 
+    /// Get sorted names of runtime macros
+    pub(crate) fn get_runtime_macro_names(&self) -> Vec<&str> {
+        self.map.runtime.get_names(self.state.hygiene)
+    }
+
+    /// Get a line number of current input
+    pub(crate) fn get_current_line_number(&self) -> usize {
+        self.logger.get_current_input_track().line_index
+    }
+
     #[inline]
     /// Find similar macro name
     pub(crate) fn get_similar_macro(
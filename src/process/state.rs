@@ -7,13 +7,14 @@ use crate::common::RadResult;
 use crate::common::{
     CommentType, ErrorBehaviour, FlowControl, Hygiene, ProcessInput, ProcessType, RelayTarget,
 };
-use crate::consts::LINE_ENDING;
+use crate::consts::{DEFAULT_MACRO_DEPTH, LINE_ENDING};
 use crate::RadError;
 use regex::Regex;
 use std::collections::{HashMap, HashSet};
 #[cfg(not(feature = "wasm"))]
 use std::path::Path;
 use std::path::PathBuf;
+use std::time::Duration;
 
 /// Processors processing state
 pub(crate) struct ProcessorState {
@@ -22,6 +23,8 @@ pub(crate) struct ProcessorState {
     pub auth_flags: AuthFlags,
     pub current_input: ProcessInput,
     pub input_stack: HashSet<PathBuf>,
+    // Macros being substituted by evalm, which is used to detect recursive substitution
+    pub evalm_stack: HashSet<String>,
     pub newline: String,
     pub paused: bool,
     pub error_cache: Option<RadError>,
@@ -43,12 +46,21 @@ pub(crate) struct ProcessorState {
     pub comment_char: Option<char>,
     pub macro_char: Option<char>,
     pub flow_control: FlowControl,
-    pub deny_newline: bool,    // This deny next-next newline
-    pub consume_newline: bool, // This consumes newline if the line was only empty
-    pub escape_newline: bool,  // This escapes right next newline
+    pub deny_newline: bool,            // This deny next-next newline
+    pub consume_newline: bool,         // This consumes newline if the line was only empty
+    pub escape_newline: bool,          // This escapes right next newline
+    pub trim_conditional_blanks: bool, // This consumes newline after an empty expansion
     pub queued: Vec<String>,
     pub regex_cache: RegexCache,
     pub lexor_escape_blanks: bool,
+    pub max_macro_depth: usize, // Zero means unlimited
+    pub shell_timeout: Option<Duration>,
+    pub strict_undefined: bool,
+    pub count_macro_calls: bool,
+    pub warn_try_errors: bool,
+    pub macro_call_stats: HashMap<String, usize>,
+    #[cfg(feature = "rand")]
+    pub rng: rand::rngs::StdRng,
 }
 
 impl ProcessorState {
@@ -57,6 +69,7 @@ impl ProcessorState {
         Self {
             current_input: ProcessInput::Stdin,
             input_stack: HashSet::new(),
+            evalm_stack: HashSet::new(),
             auth_flags: AuthFlags::new(),
             newline: LINE_ENDING.to_owned(),
             pipe_truncate: true,
@@ -78,9 +91,32 @@ impl ProcessorState {
             deny_newline: false,
             consume_newline: false,
             escape_newline: false,
+            trim_conditional_blanks: false,
             queued: vec![],
             regex_cache: RegexCache::new(),
             lexor_escape_blanks: false,
+            max_macro_depth: DEFAULT_MACRO_DEPTH,
+            shell_timeout: None,
+            strict_undefined: false,
+            count_macro_calls: false,
+            warn_try_errors: true,
+            macro_call_stats: HashMap::new(),
+            #[cfg(feature = "rand")]
+            rng: Self::new_rng(),
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    /// Create a random generator which is seeded by environment variable if given
+    fn new_rng() -> rand::rngs::StdRng {
+        use crate::consts::RANDOM_SEED_ENV;
+        use rand::SeedableRng;
+        match std::env::var(RANDOM_SEED_ENV)
+            .ok()
+            .and_then(|seed| seed.trim().parse::<u64>().ok())
+        {
+            Some(seed) => rand::rngs::StdRng::seed_from_u64(seed),
+            None => rand::rngs::StdRng::from_entropy(),
         }
     }
 
@@ -111,9 +147,24 @@ impl ProcessorState {
         if self.pipe_truncate && !ignore_truncate {
             self.pipe_map.remove(key)
         } else {
-            self.pipe_map.get(key).map(|s| s.to_owned())
+            self.peek_pipe(key)
         }
     }
+
+    /// Get a pipe with key without consuming it
+    pub fn peek_pipe(&self, key: &str) -> Option<String> {
+        self.pipe_map.get(key).map(|s| s.to_owned())
+    }
+
+    /// Get a count of pipes including an anonymous pipe
+    pub fn pipe_count(&self) -> usize {
+        self.pipe_map.len()
+    }
+
+    /// Clear all pipes
+    pub fn clear_pipes(&mut self) {
+        self.pipe_map.clear();
+    }
 }
 
 /// Cache for regex compilation
@@ -9,7 +9,7 @@ use once_cell::sync::Lazy;
 use regex::Regex;
 use std::ffi::OsStr;
 use std::io::BufRead;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 #[cfg(not(feature = "wasm"))]
 use crate::common::RelayTarget;
@@ -247,7 +247,6 @@ impl Utils {
     // NOTE : Trailing single is necessary because this only checks newline chracter
     // thus line without trailing newline doesn't count as 1
     /// Count new lines
-    #[allow(dead_code)]
     pub(crate) fn count_sentences(s: &str) -> usize {
         s.as_bytes().iter().filter(|&&c| c == b'\n').count() + 1
     }
@@ -342,6 +341,56 @@ impl Utils {
         Ok(())
     }
 
+    /// Execute a command and get its stdout, killing the command after the timeout
+    #[cfg(not(feature = "wasm"))]
+    pub(crate) fn command_output_with_timeout(
+        command: &mut std::process::Command,
+        timeout: std::time::Duration,
+    ) -> RadResult<Vec<u8>> {
+        use std::io::Read;
+        use std::process::Stdio;
+        use std::time::{Duration, Instant};
+        // Match standard streams of "Command::output" which doesn't inherit stdin and captures
+        // stderr, which is discarded by a caller
+        let mut child = command
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(|_| {
+                RadError::InvalidExecution(format!("Failed to execute command : {:?}", command))
+            })?;
+
+        // Read stdout in a separate thread so that a full pipe doesn't block a child
+        let mut stdout = child.stdout.take().unwrap();
+        let reader = std::thread::spawn(move || {
+            let mut buffer = vec![];
+            stdout.read_to_end(&mut buffer).map(|_| buffer)
+        });
+
+        let start = Instant::now();
+        loop {
+            if child.try_wait()?.is_some() {
+                break;
+            }
+            if start.elapsed() >= timeout {
+                child.kill()?;
+                child.wait()?;
+                return Err(RadError::InvalidExecution(format!(
+                    "Command {:?} timed out after {} milliseconds",
+                    command,
+                    timeout.as_millis()
+                )));
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+
+        let output = reader.join().map_err(|_| {
+            RadError::InvalidExecution(format!("Failed to read output of : {:?}", command))
+        })??;
+        Ok(output)
+    }
+
     /// This checks if a file is safely modifiable
     ///
     /// File operation can be nested and somtimes logically implausible. Such as referencing self,
@@ -423,4 +472,79 @@ impl Utils {
         }
         Ok(())
     }
+
+    /// Get sorted paths that match a glob pattern
+    ///
+    /// Only "*" and "?" wildcards are supported and they don't match path separators
+    #[cfg(not(feature = "wasm"))]
+    pub(crate) fn glob_paths(pattern: &str) -> RadResult<Vec<PathBuf>> {
+        use std::path::Component;
+        let components: Vec<Component> = Path::new(pattern).components().collect();
+        let mut candidates = vec![PathBuf::new()];
+        for (index, component) in components.iter().enumerate() {
+            let is_last = index == components.len() - 1;
+            let segment = component.as_os_str().to_string_lossy();
+            if !matches!(component, Component::Normal(_)) || !segment.contains(['*', '?']) {
+                candidates.iter_mut().for_each(|c| c.push(component));
+                continue;
+            }
+
+            let mut expanded = vec![];
+            for candidate in candidates {
+                let dir = if candidate.as_os_str().is_empty() {
+                    Path::new(".")
+                } else {
+                    candidate.as_path()
+                };
+                // Non-directory candidate cannot have children
+                let entries = match std::fs::read_dir(dir) {
+                    Ok(entries) => entries,
+                    Err(_) => continue,
+                };
+                for entry in entries {
+                    let entry = entry?;
+                    let name = entry.file_name();
+                    if !Self::wildcard_match(&segment, &name.to_string_lossy()) {
+                        continue;
+                    }
+                    if !is_last && !entry.path().is_dir() {
+                        continue;
+                    }
+                    expanded.push(candidate.join(name));
+                }
+            }
+            candidates = expanded;
+        }
+
+        let mut paths: Vec<PathBuf> = candidates.into_iter().filter(|p| p.exists()).collect();
+        paths.sort();
+        Ok(paths)
+    }
+
+    /// Check if a text matches a wildcard pattern with "*" and "?"
+    #[cfg(not(feature = "wasm"))]
+    fn wildcard_match(pattern: &str, text: &str) -> bool {
+        let pattern: Vec<char> = pattern.chars().collect();
+        let text: Vec<char> = text.chars().collect();
+        let (mut p_idx, mut t_idx) = (0, 0);
+        // Position of last star and text index it started to match from
+        let mut star: Option<(usize, usize)> = None;
+        while t_idx < text.len() {
+            if p_idx < pattern.len() && (pattern[p_idx] == '?' || pattern[p_idx] == text[t_idx]) {
+                p_idx += 1;
+                t_idx += 1;
+            } else if p_idx < pattern.len() && pattern[p_idx] == '*' {
+                star = Some((p_idx, t_idx));
+                p_idx += 1;
+            } else if let Some((star_idx, matched)) = star {
+                // Let the last star consume one more character
+                p_idx = star_idx + 1;
+                t_idx = matched + 1;
+                star = Some((star_idx, matched + 1));
+            } else {
+                return false;
+            }
+        }
+        pattern[p_idx..].iter().all(|&ch| ch == '*')
+    }
 }